@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS search_sources text[] NOT NULL DEFAULT '{ytsearch:}' CHECK (cardinality(search_sources) > 0);
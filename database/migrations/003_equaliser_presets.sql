@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS equaliser_presets (
+    guild bigint references guild_configs(id),
+    name text NOT NULL,
+    gains double precision[] NOT NULL CHECK (array_length(gains, 1) = 15),
+    PRIMARY KEY (guild, name)
+);
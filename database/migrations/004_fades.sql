@@ -0,0 +1,2 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS fade_in_secs smallint NOT NULL DEFAULT 0;
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS fade_out_secs smallint NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS default_volume smallint NOT NULL DEFAULT 100 CHECK (default_volume BETWEEN 1 AND 1000);
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS max_volume smallint NOT NULL DEFAULT 1000 CHECK (max_volume BETWEEN 1 AND 1000);
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS default_filter_preset text;
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS default_equaliser_preset text;
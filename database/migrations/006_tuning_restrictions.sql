@@ -0,0 +1,5 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS disabled_tuning_features text[] NOT NULL DEFAULT '{}';
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS min_speed double precision CHECK (min_speed > 0);
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS max_speed double precision CHECK (max_speed > 0);
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS min_pitch double precision CHECK (min_pitch > 0);
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS max_pitch double precision CHECK (max_pitch > 0);
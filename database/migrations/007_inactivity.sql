@@ -0,0 +1,3 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS inactivity_timeout_secs smallint NOT NULL DEFAULT 600 CHECK (inactivity_timeout_secs BETWEEN 30 AND 3600);
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS pause_when_alone boolean NOT NULL DEFAULT false;
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS queue_end_timeout_mins smallint NOT NULL DEFAULT 0 CHECK (queue_end_timeout_mins BETWEEN 0 AND 60);
@@ -0,0 +1,6 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS twenty_four_seven boolean NOT NULL DEFAULT false;
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS twenty_four_seven_channel bigint;
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS twenty_four_seven_text_channel bigint;
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS twenty_four_seven_queue text[] NOT NULL DEFAULT '{}';
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS twenty_four_seven_requesters bigint[] NOT NULL DEFAULT '{}';
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS twenty_four_seven_queue_index integer NOT NULL DEFAULT 0;
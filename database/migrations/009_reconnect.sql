@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS reconnect_grace_secs smallint NOT NULL DEFAULT 0 CHECK (reconnect_grace_secs BETWEEN 0 AND 600);
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS channel_status boolean NOT NULL DEFAULT false;
@@ -0,0 +1,2 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS music_channel bigint;
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS music_channel_only boolean NOT NULL DEFAULT false;
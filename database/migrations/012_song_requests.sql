@@ -0,0 +1,2 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS song_request_channel bigint;
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS song_request_message bigint;
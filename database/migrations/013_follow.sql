@@ -0,0 +1,2 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS follow_user bigint;
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS follow_dj boolean NOT NULL DEFAULT false;
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN IF NOT EXISTS self_deaf boolean NOT NULL DEFAULT true;
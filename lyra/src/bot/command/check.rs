@@ -9,10 +9,10 @@ use std::{
 
 use twilight_cache_inmemory::{model::CachedVoiceState, Reference};
 use twilight_model::{
-    channel::{message::MessageFlags, ChannelType},
+    channel::{message::MessageFlags, Channel, ChannelType},
     guild::Permissions,
     id::{
-        marker::{ChannelMarker, GuildMarker, UserMarker},
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
         Id,
     },
 };
@@ -21,7 +21,7 @@ use crate::bot::{
     command::model::{Ctx, CtxKind},
     component::config::access::CalculatorBuilder,
     core::{
-        model::{AuthorPermissionsAware, BotState, CacheAware, OwnedBotStateAware},
+        model::{AuthorPermissionsAware, BotState, BotStateAware, CacheAware, OwnedBotStateAware},
         traced,
     },
     error::{
@@ -101,14 +101,31 @@ pub async fn user_allowed_in(ctx: &Ctx<impl CtxKind>) -> Result<(), check::UserA
         return Ok(());
     };
 
+    user_allowed_in_channel(
+        guild_id,
+        ctx.author_id(),
+        ctx.member().roles.iter(),
+        ctx.channel(),
+        ctx,
+    )
+    .await
+}
+
+/// Checks the same access controls as commands do, for where there is no interaction to check against
+pub async fn user_allowed_in_channel<'a>(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    role_ids: impl Iterator<Item = &'a Id<RoleMarker>>,
+    channel: &Channel,
+    ctx: &(impl AuthorPermissionsAware + BotStateAware),
+) -> Result<(), check::UserAllowedError> {
     if user_is_access_manager(ctx).is_ok() {
         return Ok(());
     }
 
-    let channel = ctx.channel();
-    let mut access_calculator_builder = CalculatorBuilder::new(guild_id, ctx.db().clone())
-        .user(ctx.author_id())
-        .roles(ctx.member().roles.iter());
+    let mut access_calculator_builder = CalculatorBuilder::new(guild_id, ctx.bot().db().clone())
+        .user(user_id)
+        .roles(role_ids);
     match channel.kind {
         ChannelType::PublicThread
         | ChannelType::PrivateThread
@@ -143,7 +160,7 @@ pub async fn user_allowed_in(ctx: &Ctx<impl CtxKind>) -> Result<(), check::UserA
 pub async fn user_allowed_to_use(
     channel_id: Id<ChannelMarker>,
     channel_parent_id: Option<Id<ChannelMarker>>,
-    ctx: &Ctx<impl CtxKind>,
+    ctx: &(impl AuthorPermissionsAware + BotStateAware + ExpectedGuildIdAware),
 ) -> Result<(), check::UserAllowedError> {
     if user_is_access_manager(ctx).is_ok() {
         return Ok(());
@@ -151,7 +168,7 @@ pub async fn user_allowed_to_use(
 
     let guild_id = ctx.guild_id();
     let mut access_calculator_builder =
-        CalculatorBuilder::new(guild_id, ctx.db().clone()).voice_channel(channel_id);
+        CalculatorBuilder::new(guild_id, ctx.bot().db().clone()).voice_channel(channel_id);
 
     if let Some(parent_id) = channel_parent_id {
         access_calculator_builder = access_calculator_builder.category_channel(parent_id);
@@ -17,9 +17,10 @@ use crate::bot::{
         misc::Ping,
         queue::{
             AddToQueue, Clear, FairQueue, Move, MoveAutocomplete, Play, PlayAutocomplete, PlayFile,
-            Remove, RemoveAutocomplete, RemoveRange, RemoveRangeAutocomplete, Repeat, Shuffle,
+            Remove, RemoveAutocomplete, RemoveRange, RemoveRangeAutocomplete, Repeat, Search,
+            Shuffle,
         },
-        tuning::{Equaliser, Filter, Speed, Volume},
+        tuning::{Equaliser, EqualiserAutocomplete, Filter, Speed, Tuning, Volume},
     },
     error::command::declare::{AutocompleteExecuteError, CommandExecuteError},
 };
@@ -137,6 +138,7 @@ declare_slash_commands![
     Config,
     Play,
     PlayFile,
+    Search,
     Repeat,
     Shuffle,
     FairQueue,
@@ -148,6 +150,7 @@ declare_slash_commands![
     Filter,
     Speed,
     Equaliser,
+    Tuning,
 ];
 declare_message_commands![AddToQueue,];
 
@@ -156,4 +159,5 @@ declare_autocomplete![
     Remove => RemoveAutocomplete,
     RemoveRange => RemoveRangeAutocomplete,
     Move => MoveAutocomplete,
+    Equaliser => EqualiserAutocomplete,
 ];
@@ -84,23 +84,23 @@ macro_rules! hid_fol {
     };
 }
 
-// macro_rules! hid_or_fol {
-//     ($cnt: expr, $ctx: expr) => {
-//         if $ctx.acknowledged() {
-//             $ctx.followup_ephem(&$cnt).await?;
-//             return Ok(());
-//         }
-//         $ctx.ephem($cnt).await?;
-//         return Ok(());
-//     };
-//     ($cnt: expr, ?$ctx: expr) => {
-//         if $ctx.acknowledged() {
-//             $ctx.followup_ephem(&$cnt).await?;
-//         } else {
-//             $ctx.ephem($cnt).await?;
-//         }
-//     };
-// }
+macro_rules! hid_or_fol {
+    ($cnt: expr, $ctx: expr) => {
+        if $ctx.acknowledged() {
+            $ctx.followup_ephem(&$cnt).await?;
+            return Ok(());
+        }
+        $ctx.ephem($cnt).await?;
+        return Ok(());
+    };
+    ($cnt: expr, ?$ctx: expr) => {
+        if $ctx.acknowledged() {
+            $ctx.followup_ephem(&$cnt).await?;
+        } else {
+            $ctx.ephem($cnt).await?;
+        }
+    };
+}
 
 macro_rules! generate_hid_variants {
     ($($name: ident => $emoji: ident),+$(,)?) => {
@@ -138,24 +138,22 @@ macro_rules! generate_hid_fol_variants {
     }
 }
 
-// macro_rules! generate_hid_or_fol_variants {
-//     ($($name: ident => $emoji: ident),+$(,)?) => {
-//         $(
-//             macro_rules! $name {
-//                 ($cnt: expr, $ctx: expr) => {
-//                     use crate::bot::core::consts::exit_code;
-//                     hid_or_fol!(format!("{} {}", exit_code::$emoji, $cnt), $ctx);
-//                 };
-//                 ($cnt: expr, ?$ctx: expr) => {
-//                     use crate::bot::core::consts::exit_code;
-//                     hid_or_fol!(format!("{} {}", exit_code::$emoji, $cnt), ?$ctx);
-//                 };
-//             }
-//         )+
-
-//         pub(crate) use {$($name,)+};
-//     }
-// }
+macro_rules! generate_hid_or_fol_variants {
+    ($($name: ident => $emoji: ident),+$(,)?) => {
+        $(
+            macro_rules! $name {
+                ($cnt: expr, $ctx: expr) => {
+                    crate::bot::command::macros::hid_or_fol!(format!("{} {}", crate::bot::core::r#const::exit_code::$emoji, $cnt), $ctx);
+                };
+                ($cnt: expr, ?$ctx: expr) => {
+                    crate::bot::command::macros::hid_or_fol!(format!("{} {}", crate::bot::core::r#const::exit_code::$emoji, $cnt), ?$ctx);
+                };
+            }
+        )+
+
+        pub(crate) use {$($name,)+};
+    }
+}
 
 generate_hid_variants! {
     note => NOTICE,
@@ -172,7 +170,7 @@ generate_hid_variants! {
 generate_hid_fol_variants! {
     note_fol => NOTICE,
     sus_fol => DUBIOUS,
-    // caut_fol => WARNING,
+    caut_fol => WARNING,
     // miss_fol => NOT_FOUND,
     // bad_fol => INVALID,
     // nope_fol => PROHIBITED,
@@ -181,16 +179,16 @@ generate_hid_fol_variants! {
     // crit_fol => UNKNOWN_ERROR
 }
 
-// generate_hid_or_fol_variants! {
-//     note_or_fol => NOTICE,
-//     dub_or_fol => DUBIOUS,
-//     caut_or_fol => WARNING,
-//     miss_or_fol => NOT_FOUND,
-//     bad_or_fol => INVALID,
-//     nope_or_fol => PROHIBITED,
-//     cant_or_fol => FORBIDDEN,
-//     err_or_fol => KNOWN_ERROR,
-//     crit_or_fol => UNKNOWN_ERROR
-// }
+generate_hid_or_fol_variants! {
+    // note_or_fol => NOTICE,
+    // dub_or_fol => DUBIOUS,
+    // caut_or_fol => WARNING,
+    what_or_fol => NOT_FOUND,
+    bad_or_fol => INVALID,
+    // nope_or_fol => PROHIBITED,
+    // cant_or_fol => FORBIDDEN,
+    // err_or_fol => KNOWN_ERROR,
+    crit_or_fol => UNKNOWN_ERROR
+}
 
-pub(crate) use {hid, hid_fol, out, out_or_fol, out_or_upd, out_upd};
+pub(crate) use {hid, hid_fol, hid_or_fol, out, out_or_fol, out_or_upd, out_upd};
@@ -16,8 +16,8 @@ use twilight_model::{
 use crate::bot::error::{command::AutocompleteResult, CommandResult};
 
 pub use self::ctx::{
-    AutocompleteCtx, CommandDataAware, Ctx, CtxKind, MessageCtx, ModalCtx, RespondViaMessage,
-    RespondViaModal, SlashCtx, UserCtx,
+    AutocompleteCtx, CommandDataAware, ComponentCtx, Ctx, CtxKind, MessageCtx, ModalCtx,
+    RespondViaMessage, RespondViaModal, SlashCtx, UserCtx,
 };
 
 #[derive(Debug)]
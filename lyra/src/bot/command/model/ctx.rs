@@ -94,6 +94,18 @@ impl<T: CtxKind> Ctx<T> {
         }
     }
 
+    pub fn to_component_interaction(&self, inner: Box<InteractionCreate>) -> ComponentCtx {
+        Ctx {
+            inner,
+            bot: self.bot.clone(),
+            latency: self.latency.clone(),
+            sender: self.sender.clone(),
+            data: None,
+            acknowledged: false,
+            kind: PhantomData::<fn(ComponentMarker) -> ComponentMarker>,
+        }
+    }
+
     pub const fn latency(&self) -> &Latency {
         &self.latency
     }
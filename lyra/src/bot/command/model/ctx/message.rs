@@ -43,6 +43,76 @@ impl<T: RespondViaMessage> Ctx<T> {
             .await?)
     }
 
+    pub async fn update_no_components(&mut self) -> MessageFollowupResult {
+        Ok(self.interface().await?.update_no_components().await?)
+    }
+
+    pub async fn update_content_and_components(
+        &self,
+        content: &str,
+        components: &[Component],
+    ) -> MessageFollowupResult {
+        Ok(self
+            .interface()
+            .await?
+            .update_content_and_components(content, components)
+            .await?)
+    }
+
+    pub async fn defer(&mut self) -> RespondResult<()> {
+        let response = self.interface().await?.defer().await;
+        self.acknowledge();
+        Ok(response?)
+    }
+
+    pub async fn defer_update(&mut self) -> RespondResult<()> {
+        let response = self.interface().await?.defer_update().await;
+        self.acknowledge();
+        Ok(response?)
+    }
+
+    pub async fn update_embeds_and_components(
+        &self,
+        embeds: &[Embed],
+        components: &[Component],
+    ) -> MessageFollowupResult {
+        Ok(self
+            .interface()
+            .await?
+            .update_embeds_and_components(embeds, components)
+            .await?)
+    }
+
+    pub async fn update_message_embeds_and_components(
+        &mut self,
+        embeds: impl IntoIterator<Item = Embed> + Send,
+        components: impl IntoIterator<Item = Component> + Send,
+    ) -> MessageRespondResult {
+        let data = Self::base_response_data_builder()
+            .embeds(embeds)
+            .components(components)
+            .build();
+        let response = self
+            .interface()
+            .await?
+            .update_message_with(Some(data))
+            .await;
+        self.acknowledge();
+        Ok(response?)
+    }
+
+    pub async fn respond_with_components(
+        &mut self,
+        content: impl Into<String> + Send,
+        components: impl IntoIterator<Item = Component> + Send,
+    ) -> MessageRespondResult {
+        let data = Self::base_response_data_builder()
+            .content(content)
+            .components(components)
+            .build();
+        self.respond_with(Some(data)).await
+    }
+
     pub async fn respond_embeds_only(
         &mut self,
         embeds: impl IntoIterator<Item = Embed> + Send,
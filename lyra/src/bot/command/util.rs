@@ -1,5 +1,4 @@
 use chrono::Utc;
-use lavalink_rs::error::LavalinkResult;
 use rand::{distributions::Alphanumeric, Rng};
 use twilight_gateway::Event;
 use twilight_model::{
@@ -24,7 +23,7 @@ use super::{
     ModalCtx,
 };
 use crate::bot::{
-    component::connection::auto_join,
+    component::{connection::auto_join, tuning},
     core::{
         model::{BotStateAware, CacheAware, OwnedBotStateAware},
         r#const::{
@@ -36,8 +35,9 @@ use crate::bot::{
         command::{
             check::NotSuppressedError,
             util::{
-                AutoJoinOrCheckInVoiceWithUserError, AutoJoinSuppressedError, ConfirmationError,
-                HandleSuppressedAutoJoinError, PromptForConfirmationError,
+                AutoJoinOrCheckInVoiceWithUserError, AutoJoinSuppressedError,
+                AutoNewPlayerDataError, ConfirmationError, HandleSuppressedAutoJoinError,
+                PromptForConfirmationError,
             },
         },
         Suppressed as SuppressedError,
@@ -267,11 +267,18 @@ async fn handle_suppressed_auto_join(
 }
 
 pub async fn prompt_for_confirmation(
+    ctx: Ctx<impl CommandDataAware + RespondViaModal>,
+) -> Result<ModalCtx, PromptForConfirmationError> {
+    prompt_for_confirmation_with(ctx, "This is a destructive command. Are you sure?").await
+}
+
+pub async fn prompt_for_confirmation_with(
     mut ctx: Ctx<impl CommandDataAware + RespondViaModal>,
+    label: impl Into<String> + Send,
 ) -> Result<ModalCtx, PromptForConfirmationError> {
     let text_input = TextInput {
         custom_id: String::new(),
-        label: "This is a destructive command. Are you sure?".into(),
+        label: label.into(),
         max_length: None,
         min_length: None,
         required: true.into(),
@@ -317,7 +324,7 @@ pub async fn prompt_for_confirmation(
     let modal_ctx = match wait_for_modal_submit {
         Ok(Ok(Event::InteractionCreate(interaction))) => {
             let ctx = ctx.into_modal_interaction(interaction);
-            if ctx.submit_data().components[0].components[0]
+            if !ctx.submit_data().components[0].components[0]
                 .value
                 .as_ref()
                 .is_some_and(|s| s == "YES")
@@ -334,12 +341,13 @@ pub async fn prompt_for_confirmation(
     Ok(modal_ctx)
 }
 
-pub async fn auto_new_player_data(ctx: &Ctx<impl CtxKind>) -> LavalinkResult<()> {
+pub async fn auto_new_player_data(ctx: &Ctx<impl CtxKind>) -> Result<(), AutoNewPlayerDataError> {
     let guild_id = ctx.guild_id();
     let lavalink = ctx.lavalink();
 
     if lavalink.get_player_data(guild_id).is_none() {
-        lavalink.new_player_data(guild_id).await?;
+        let defaults = tuning::player_defaults(ctx.db(), guild_id).await?;
+        lavalink.new_player_data(guild_id, defaults).await?;
     }
     Ok(())
 }
@@ -1,11 +1,28 @@
 pub mod access;
+pub mod channel_status;
+pub mod defaults;
+pub mod fade;
+pub mod follow;
+pub mod inactivity;
+pub mod music_channel;
 pub mod now_playing;
+pub mod reconnect;
+pub mod search_source;
+pub mod self_deafen;
+pub mod song_requests;
+pub mod tuning;
+pub mod twenty_four_seven;
 
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use lyra_proc::BotCommandGroup;
 
-use self::{access::Access, now_playing::NowPlaying};
+use self::{
+    access::Access, channel_status::ChannelStatus, defaults::Defaults, fade::Fade, follow::Follow,
+    inactivity::Inactivity, music_channel::MusicChannel, now_playing::NowPlaying,
+    reconnect::Reconnect, search_source::SearchSource, self_deafen::SelfDeafen,
+    song_requests::SongRequests, tuning::Tuning, twenty_four_seven::TwentyFourSeven,
+};
 
 #[derive(CommandModel, CreateCommand, BotCommandGroup)]
 #[command(name = "config", desc = ".", dm_permission = false)]
@@ -14,4 +31,28 @@ pub enum Config {
     Access(Box<Access>),
     #[command(name = "now-playing")]
     NowPlaying(NowPlaying),
+    #[command(name = "search-source")]
+    SearchSource(SearchSource),
+    #[command(name = "fade")]
+    Fade(Fade),
+    #[command(name = "defaults")]
+    Defaults(Defaults),
+    #[command(name = "tuning")]
+    Tuning(Tuning),
+    #[command(name = "inactivity")]
+    Inactivity(Inactivity),
+    #[command(name = "24-7")]
+    TwentyFourSeven(TwentyFourSeven),
+    #[command(name = "reconnect")]
+    Reconnect(Reconnect),
+    #[command(name = "channel-status")]
+    ChannelStatus(ChannelStatus),
+    #[command(name = "music-channel")]
+    MusicChannel(MusicChannel),
+    #[command(name = "song-requests")]
+    SongRequests(SongRequests),
+    #[command(name = "follow")]
+    Follow(Follow),
+    #[command(name = "self-deafen")]
+    SelfDeafen(SelfDeafen),
 }
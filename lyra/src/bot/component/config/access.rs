@@ -209,6 +209,49 @@ impl AccessCategoryFlags {
     }
 }
 
+async fn prune(
+    db: &Pool<Postgres>,
+    category_flags: AccessCategoryFlags,
+    guild_id: Id<GuildMarker>,
+    id: u64,
+) -> Result<(), sqlx::Error> {
+    for column in category_flags.iter_names_as_column() {
+        sqlx::query(&format!(
+            "--sql
+            DELETE FROM {column} WHERE guild = $1 AND id = $2;"
+        ))
+        .bind(guild_id.get() as i64)
+        .bind(id as i64)
+        .execute(db)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Removes a deleted channel or thread from the channel access controls
+pub async fn prune_channel(
+    db: &Pool<Postgres>,
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+) -> Result<(), sqlx::Error> {
+    prune(
+        db,
+        AccessCategoryFlags::ALL_CHANNELS,
+        guild_id,
+        channel_id.get(),
+    )
+    .await
+}
+
+/// Removes a deleted role from the role access controls
+pub async fn prune_role(
+    db: &Pool<Postgres>,
+    guild_id: Id<GuildMarker>,
+    role_id: Id<RoleMarker>,
+) -> Result<(), sqlx::Error> {
+    prune(db, AccessCategoryFlags::ROLES, guild_id, role_id.get()).await
+}
+
 #[derive(CommandModel, CreateCommand, BotCommandGroup)]
 #[command(name = "access", desc = ".")]
 pub enum Access {
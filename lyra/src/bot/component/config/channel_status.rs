@@ -0,0 +1,47 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::{check, macros::out, model::BotSlashCommand, SlashCtx},
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+};
+use lyra_proc::BotCommandGroup;
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "channel-status", desc = ".")]
+pub enum ChannelStatus {
+    #[command(name = "toggle")]
+    Toggle(Toggle),
+}
+
+/// Toggles whether the stage topic or voice channel status should show the current track
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "toggle")]
+pub struct Toggle;
+
+impl BotSlashCommand for Toggle {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let new_channel_status = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET channel_status = NOT channel_status WHERE id = $1 RETURNING channel_status;
+            ",
+            ctx.guild_id().get() as i64,
+        )
+        .fetch_one(ctx.db())
+        .await?
+        .channel_status;
+
+        let (emoji, action) = if new_channel_status {
+            ("🏷️", "Showing")
+        } else {
+            ("🔇", "Not showing")
+        };
+
+        out!(
+            format!("{emoji} **{action}** the current track in the stage topic or voice channel status from now on."),
+            ctx
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::{
+        check,
+        macros::{out, what},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    component::tuning::{resolve_equaliser_gains, FilterPreset},
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+};
+use lyra_proc::BotCommandGroup;
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "defaults", desc = ".")]
+pub enum Defaults {
+    #[command(name = "volume")]
+    Volume(Volume),
+    #[command(name = "max-volume")]
+    MaxVolume(MaxVolume),
+    #[command(name = "filter")]
+    Filter(Filter),
+    #[command(name = "equaliser")]
+    Equaliser(Equaliser),
+    #[command(name = "reset")]
+    Reset(Reset),
+}
+
+/// Sets the volume new players start with
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "volume")]
+pub struct Volume {
+    /// Start at what percentage? [1~1000%]
+    #[command(min_value = 1, max_value = 1_000)]
+    percent: i64,
+}
+
+impl BotSlashCommand for Volume {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        let max_volume = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET default_volume = $2 WHERE id = $1 RETURNING max_volume;
+            ",
+            ctx.guild_id().get() as i64,
+            self.percent as i16,
+        )
+        .fetch_one(ctx.db())
+        .await?
+        .max_volume;
+
+        let capped_note = (i64::from(max_volume) < self.percent)
+            .then(|| format!(" (**`Capped to the max volume of {max_volume}%`**)"))
+            .unwrap_or_default();
+        out!(
+            format!(
+                "🔊 New players will start at `{}%` volume{capped_note}.",
+                self.percent
+            ),
+            ctx
+        );
+    }
+}
+
+/// Sets the highest volume the playback volume can be set to
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "max-volume")]
+pub struct MaxVolume {
+    /// Allow up to what percentage? [1~1000%]
+    #[command(min_value = 1, max_value = 1_000)]
+    percent: i64,
+}
+
+impl BotSlashCommand for MaxVolume {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET max_volume = $2 WHERE id = $1;
+            ",
+            ctx.guild_id().get() as i64,
+            self.percent as i16,
+        )
+        .execute(ctx.db())
+        .await?;
+
+        out!(
+            format!(
+                "🔊 The playback volume can be set up to `{}%` from now on.",
+                self.percent
+            ),
+            ctx
+        );
+    }
+}
+
+/// Sets the filter preset new players start with
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "filter")]
+pub struct Filter {
+    /// Start with which preset? (If not given, none)
+    preset: Option<FilterPreset>,
+}
+
+impl BotSlashCommand for Filter {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        let preset_name = self.preset.as_ref().map(FilterPreset::value);
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET default_filter_preset = $2 WHERE id = $1;
+            ",
+            ctx.guild_id().get() as i64,
+            preset_name,
+        )
+        .execute(ctx.db())
+        .await?;
+
+        let Some(preset_name) = preset_name else {
+            out!("🪄 New players will start without a filter preset.", ctx);
+        };
+        out!(
+            format!("🪄 New players will start with the **`{preset_name}`** filter preset."),
+            ctx
+        );
+    }
+}
+
+/// Sets the equaliser preset new players start with
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "equaliser")]
+pub struct Equaliser {
+    /// Start with which preset, built-in or saved in this server? (If not given, none)
+    preset: Option<String>,
+}
+
+impl BotSlashCommand for Equaliser {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        if let Some(ref preset) = self.preset {
+            if resolve_equaliser_gains(ctx.db(), ctx.guild_id(), preset)
+                .await?
+                .is_none()
+            {
+                what!(
+                    format!("No equaliser preset named **`{preset}`** exists."),
+                    ctx
+                );
+            }
+        }
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET default_equaliser_preset = $2 WHERE id = $1;
+            ",
+            ctx.guild_id().get() as i64,
+            self.preset,
+        )
+        .execute(ctx.db())
+        .await?;
+
+        let Some(preset) = self.preset else {
+            out!(
+                "🎛️ New players will start without an equaliser preset.",
+                ctx
+            );
+        };
+        out!(
+            format!("🎛️ New players will start with the **`{preset}`** equaliser preset."),
+            ctx
+        );
+    }
+}
+
+/// Resets the volume, max volume, and presets new players start with
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reset")]
+pub struct Reset;
+
+impl BotSlashCommand for Reset {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs
+            SET
+                default_volume = DEFAULT,
+                max_volume = DEFAULT,
+                default_filter_preset = DEFAULT,
+                default_equaliser_preset = DEFAULT
+            WHERE id = $1;
+            ",
+            ctx.guild_id().get() as i64,
+        )
+        .execute(ctx.db())
+        .await?;
+
+        out!(
+            "🔊 New players will start at `100%` volume without any presets, with the playback volume allowed up to `1000%`.",
+            ctx
+        );
+    }
+}
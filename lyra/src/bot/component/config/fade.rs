@@ -0,0 +1,101 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::{
+        check,
+        macros::{bad, out},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    core::r#const::misc::FADE_SECS_MAX,
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+};
+use lyra_proc::BotCommandGroup;
+
+fn fade_text(secs: i16) -> String {
+    if secs == 0 {
+        return String::from("**off**");
+    }
+    format!("**`{secs}s`**")
+}
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "fade", desc = ".")]
+pub enum Fade {
+    #[command(name = "set")]
+    Set(Set),
+    #[command(name = "reset")]
+    Reset(Reset),
+}
+
+/// Sets how long tracks should fade in when starting and fade out when ending or leaving
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "set")]
+pub struct Set {
+    /// Fade in for how many seconds? [0 to turn off] (If not given, unchanged)
+    #[command(min_value = 0, max_value = 10)]
+    fade_in: Option<i64>,
+    /// Fade out for how many seconds? [0 to turn off] (If not given, unchanged)
+    #[command(min_value = 0, max_value = 10)]
+    fade_out: Option<i64>,
+}
+
+impl BotSlashCommand for Set {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        if self.fade_in.is_none() && self.fade_out.is_none() {
+            bad!("At least one of fade in or fade out must be given.", ctx);
+        }
+
+        let fade_in = self.fade_in.map(|s| s.min(FADE_SECS_MAX) as i16);
+        let fade_out = self.fade_out.map(|s| s.min(FADE_SECS_MAX) as i16);
+        let new_fade = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs
+            SET
+                fade_in_secs = COALESCE($2, fade_in_secs),
+                fade_out_secs = COALESCE($3, fade_out_secs)
+            WHERE id = $1
+            RETURNING fade_in_secs, fade_out_secs;
+            ",
+            ctx.guild_id().get() as i64,
+            fade_in,
+            fade_out,
+        )
+        .fetch_one(ctx.db())
+        .await?;
+
+        out!(
+            format!(
+                "🌅 Fading in {} and fading out {} from now on.",
+                fade_text(new_fade.fade_in_secs),
+                fade_text(new_fade.fade_out_secs),
+            ),
+            ctx
+        );
+    }
+}
+
+/// Turns off fading in and out of tracks
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reset")]
+pub struct Reset;
+
+impl BotSlashCommand for Reset {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET fade_in_secs = DEFAULT, fade_out_secs = DEFAULT WHERE id = $1;
+            ",
+            ctx.guild_id().get() as i64,
+        )
+        .execute(ctx.db())
+        .await?;
+
+        out!("🌅 Not fading tracks in or out from now on.", ctx);
+    }
+}
@@ -0,0 +1,144 @@
+use sqlx::{Pool, Postgres};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::Mention;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::bot::{
+    command::{
+        check,
+        macros::{note, out},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+};
+use lyra_proc::BotCommandGroup;
+
+/// Whom the bot moves along with between voice channels, as configured in `guild_configs`
+pub enum FollowTarget {
+    User(Id<UserMarker>),
+    /// Whoever requested the currently playing track
+    Dj,
+}
+
+impl FollowTarget {
+    pub async fn fetch(
+        db: &Pool<Postgres>,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        let config = sqlx::query!(
+            r"--sql
+            SELECT follow_user, follow_dj FROM guild_configs WHERE id = $1;",
+            guild_id.get() as i64
+        )
+        .fetch_one(db)
+        .await?;
+
+        if config.follow_dj {
+            return Ok(Some(Self::Dj));
+        }
+        Ok(config
+            .follow_user
+            .map(|user_id| Self::User(Id::new(user_id as u64))))
+    }
+}
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "follow", desc = ".")]
+pub enum Follow {
+    #[command(name = "user")]
+    User(User),
+    #[command(name = "dj")]
+    Dj(Dj),
+    #[command(name = "off")]
+    Off(Off),
+}
+
+/// Moves along with a member whenever they move between voice channels
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "user")]
+pub struct User {
+    /// Which member?
+    member: Id<UserMarker>,
+}
+
+impl BotSlashCommand for User {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET follow_user = $2, follow_dj = false WHERE id = $1;
+            ",
+            ctx.guild_id().get() as i64,
+            self.member.get() as i64,
+        )
+        .execute(ctx.db())
+        .await?;
+
+        out!(
+            format!(
+                "👣 Following {} between voice channels from now on.",
+                self.member.mention()
+            ),
+            ctx
+        );
+    }
+}
+
+/// Moves along with whoever requested the current track whenever they move between voice channels
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "dj")]
+pub struct Dj;
+
+impl BotSlashCommand for Dj {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET follow_user = NULL, follow_dj = true WHERE id = $1;
+            ",
+            ctx.guild_id().get() as i64,
+        )
+        .execute(ctx.db())
+        .await?;
+
+        out!(
+            "👣 Following whoever requested the current track between voice channels from now on.",
+            ctx
+        );
+    }
+}
+
+/// Stops moving along with anyone between voice channels
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "off")]
+pub struct Off;
+
+impl BotSlashCommand for Off {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let rows_affected = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs
+            SET follow_user = NULL, follow_dj = DEFAULT
+            WHERE id = $1 AND (follow_user IS NOT NULL OR follow_dj);
+            ",
+            ctx.guild_id().get() as i64,
+        )
+        .execute(ctx.db())
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            note!("Follow mode is already off.", ctx);
+        }
+        out!("👣 Turned follow mode off.", ctx);
+    }
+}
@@ -0,0 +1,131 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::{
+        check,
+        macros::{bad, out},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+};
+use lyra_proc::BotCommandGroup;
+
+fn settings_text(timeout_secs: i16, pause_when_alone: bool, queue_end_timeout_mins: i16) -> String {
+    let alone = if pause_when_alone {
+        format!("pausing when everyone leaves and resuming when someone returns, then leaving after **`{timeout_secs}s`** alone")
+    } else {
+        format!("leaving after **`{timeout_secs}s`** alone")
+    };
+    let queue_end = if queue_end_timeout_mins == 0 {
+        String::from("staying after the queue ends")
+    } else {
+        format!("leaving **`{queue_end_timeout_mins}m`** after the queue ends")
+    };
+    format!("{alone}, and {queue_end}")
+}
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "inactivity", desc = ".")]
+pub enum Inactivity {
+    #[command(name = "set")]
+    Set(Set),
+    #[command(name = "reset")]
+    Reset(Reset),
+}
+
+/// Sets how the bot behaves when no one is listening
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "set")]
+pub struct Set {
+    /// Leave after how many seconds alone? [30~3600s] (If not given, unchanged)
+    #[command(min_value = 30, max_value = 3_600)]
+    timeout: Option<i64>,
+    /// Pause when everyone leaves, and resume when someone returns? (If not given, unchanged)
+    pause_when_alone: Option<bool>,
+    /// Leave how many minutes after the queue ends? [0 to turn off] (If not given, unchanged)
+    #[command(min_value = 0, max_value = 60)]
+    queue_end_timeout: Option<i64>,
+}
+
+impl BotSlashCommand for Set {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        if self.timeout.is_none()
+            && self.pause_when_alone.is_none()
+            && self.queue_end_timeout.is_none()
+        {
+            bad!("At least one setting must be given.", ctx);
+        }
+
+        let new_settings = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs
+            SET
+                inactivity_timeout_secs = COALESCE($2, inactivity_timeout_secs),
+                pause_when_alone = COALESCE($3, pause_when_alone),
+                queue_end_timeout_mins = COALESCE($4, queue_end_timeout_mins)
+            WHERE id = $1
+            RETURNING inactivity_timeout_secs, pause_when_alone, queue_end_timeout_mins;
+            ",
+            ctx.guild_id().get() as i64,
+            self.timeout.map(|s| s as i16),
+            self.pause_when_alone,
+            self.queue_end_timeout.map(|m| m as i16),
+        )
+        .fetch_one(ctx.db())
+        .await?;
+
+        out!(
+            format!(
+                "💤 From now on, {}.",
+                settings_text(
+                    new_settings.inactivity_timeout_secs,
+                    new_settings.pause_when_alone,
+                    new_settings.queue_end_timeout_mins,
+                )
+            ),
+            ctx
+        );
+    }
+}
+
+/// Resets the bot's inactivity behaviour to the defaults
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reset")]
+pub struct Reset;
+
+impl BotSlashCommand for Reset {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let new_settings = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs
+            SET
+                inactivity_timeout_secs = DEFAULT,
+                pause_when_alone = DEFAULT,
+                queue_end_timeout_mins = DEFAULT
+            WHERE id = $1
+            RETURNING inactivity_timeout_secs, pause_when_alone, queue_end_timeout_mins;
+            ",
+            ctx.guild_id().get() as i64,
+        )
+        .fetch_one(ctx.db())
+        .await?;
+
+        out!(
+            format!(
+                "💤 Reset to {}.",
+                settings_text(
+                    new_settings.inactivity_timeout_secs,
+                    new_settings.pause_when_alone,
+                    new_settings.queue_end_timeout_mins,
+                )
+            ),
+            ctx
+        );
+    }
+}
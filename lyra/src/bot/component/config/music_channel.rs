@@ -0,0 +1,162 @@
+use sqlx::{Pool, Postgres};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::Mention;
+use twilight_model::{
+    application::interaction::InteractionChannel,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+
+use crate::bot::{
+    command::{
+        check,
+        macros::{note, out},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    core::model::{BotState, BotStateAware},
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+    lavalink::LavalinkAware,
+};
+use lyra_proc::BotCommandGroup;
+
+/// The text channel a guild has bound all bot notices to, as configured in `guild_configs`
+#[derive(Clone, Copy)]
+pub struct MusicChannelSettings {
+    channel_id: Id<ChannelMarker>,
+    only: bool,
+}
+
+impl MusicChannelSettings {
+    /// Reads through [`BotState::music_channels`], as this is checked for every interaction
+    pub async fn get(
+        bot: &BotState,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        bot.music_channels()
+            .get_or_fetch(guild_id, || Self::fetch(bot.db(), guild_id))
+            .await
+    }
+
+    async fn fetch(
+        db: &Pool<Postgres>,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        let settings = sqlx::query!(
+            r"--sql
+            SELECT music_channel, music_channel_only FROM guild_configs WHERE id = $1;",
+            guild_id.get() as i64
+        )
+        .fetch_one(db)
+        .await?;
+
+        Ok(settings.music_channel.map(|channel_id| Self {
+            channel_id: Id::new(channel_id as u64),
+            only: settings.music_channel_only,
+        }))
+    }
+
+    pub const fn channel_id(&self) -> Id<ChannelMarker> {
+        self.channel_id
+    }
+
+    /// Whether commands used outside of the music channel should be rejected
+    pub fn rejects(&self, channel_id: Id<ChannelMarker>) -> bool {
+        self.only && self.channel_id != channel_id
+    }
+}
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "music-channel", desc = ".")]
+pub enum MusicChannel {
+    #[command(name = "set")]
+    Set(Set),
+    #[command(name = "reset")]
+    Reset(Reset),
+}
+
+/// Binds all bot notices to a text channel, optionally rejecting commands used elsewhere
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "set")]
+pub struct Set {
+    /// Which channel?
+    #[command(channel_types = "guild_text")]
+    channel: InteractionChannel,
+    /// Reject commands used outside of the channel? (If not given, no)
+    only: Option<bool>,
+}
+
+impl BotSlashCommand for Set {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let guild_id = ctx.guild_id();
+        let channel_id = self.channel.id;
+        let only = self.only.unwrap_or_default();
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET music_channel = $2, music_channel_only = $3 WHERE id = $1;
+            ",
+            guild_id.get() as i64,
+            channel_id.get() as i64,
+            only,
+        )
+        .execute(ctx.db())
+        .await?;
+        ctx.bot().music_channels().invalidate(guild_id);
+        if let Some(mut connection) = ctx.lavalink().get_connection_mut(guild_id) {
+            connection.text_channel_id = channel_id;
+        }
+
+        let rejecting = if only {
+            ", and rejecting commands used elsewhere"
+        } else {
+            ""
+        };
+        out!(
+            format!(
+                "🎼 Sending all notices to {}{} from now on.",
+                channel_id.mention(),
+                rejecting
+            ),
+            ctx
+        );
+    }
+}
+
+/// Unbinds bot notices from the music channel, sending them wherever commands were last used
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reset")]
+pub struct Reset;
+
+impl BotSlashCommand for Reset {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let guild_id = ctx.guild_id();
+        let rows_affected = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs
+            SET music_channel = NULL, music_channel_only = DEFAULT
+            WHERE id = $1 AND music_channel IS NOT NULL;
+            ",
+            guild_id.get() as i64,
+        )
+        .execute(ctx.db())
+        .await?
+        .rows_affected();
+        ctx.bot().music_channels().invalidate(guild_id);
+
+        if rows_affected == 0 {
+            note!("No music channel is set.", ctx);
+        }
+        out!(
+            "🎼 Sending notices wherever commands were last used from now on.",
+            ctx
+        );
+    }
+}
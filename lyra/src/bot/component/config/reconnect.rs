@@ -0,0 +1,79 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::{check, macros::out, model::BotSlashCommand, SlashCtx},
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+};
+use lyra_proc::BotCommandGroup;
+
+fn grace_text(secs: i16) -> String {
+    if secs == 0 {
+        return String::from(
+            "Discarding the queue when forcefully disconnected, and not reconnecting after connection drops",
+        );
+    }
+    format!("Keeping the queue for **`{secs}s`** when forcefully disconnected, and reconnecting after connection drops")
+}
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "reconnect", desc = ".")]
+pub enum Reconnect {
+    #[command(name = "set")]
+    Set(Set),
+    #[command(name = "reset")]
+    Reset(Reset),
+}
+
+/// Sets how long the queue is kept for after a forced disconnect, offering to reconnect with it
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "set")]
+pub struct Set {
+    /// Keep the queue for how many seconds? [0 to turn off]
+    #[command(min_value = 0, max_value = 600)]
+    grace: i64,
+}
+
+impl BotSlashCommand for Set {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let new_grace = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET reconnect_grace_secs = $2 WHERE id = $1
+            RETURNING reconnect_grace_secs;
+            ",
+            ctx.guild_id().get() as i64,
+            self.grace as i16,
+        )
+        .fetch_one(ctx.db())
+        .await?
+        .reconnect_grace_secs;
+
+        out!(format!("🖇️ {} from now on.", grace_text(new_grace)), ctx);
+    }
+}
+
+/// Stops keeping the queue after forced disconnects, and stops reconnecting after connection drops
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reset")]
+pub struct Reset;
+
+impl BotSlashCommand for Reset {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let new_grace = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET reconnect_grace_secs = DEFAULT WHERE id = $1
+            RETURNING reconnect_grace_secs;
+            ",
+            ctx.guild_id().get() as i64,
+        )
+        .fetch_one(ctx.db())
+        .await?
+        .reconnect_grace_secs;
+
+        out!(format!("🖇️ {} from now on.", grace_text(new_grace)), ctx);
+    }
+}
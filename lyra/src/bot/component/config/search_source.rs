@@ -0,0 +1,98 @@
+use itertools::Itertools;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::{check, macros::out, model::BotSlashCommand, SlashCtx},
+    component::queue::PlaySource,
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+};
+use lyra_proc::BotCommandGroup;
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "search-source", desc = ".")]
+pub enum SearchSource {
+    #[command(name = "set")]
+    Set(Set),
+    #[command(name = "reset")]
+    Reset(Reset),
+}
+
+/// Sets the default source to search tracks from, and which sources to fall back to if nothing was found
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "set")]
+pub struct Set {
+    /// Search from where by default?
+    source: PlaySource,
+    /// ...if nothing was found, then from where?
+    fallback: Option<PlaySource>,
+    /// ...if still nothing was found, then from where? (2)
+    fallback_2: Option<PlaySource>,
+    /// ...if still nothing was found, then from where? (3)
+    fallback_3: Option<PlaySource>,
+}
+
+impl BotSlashCommand for Set {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let sources = [
+            Some(self.source),
+            self.fallback,
+            self.fallback_2,
+            self.fallback_3,
+        ]
+        .into_iter()
+        .flatten()
+        .unique()
+        .collect::<Vec<_>>();
+        let search_prefixes = sources.iter().map(|s| s.value()).collect::<Vec<_>>();
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET search_sources = $2 WHERE id = $1;
+            ",
+            ctx.guild_id().get() as i64,
+            &search_prefixes as &[&str],
+        )
+        .execute(ctx.db())
+        .await?;
+
+        let sources_text = sources
+            .iter()
+            .map(|s| format!("**{}**", s.name()))
+            .join(" → ");
+        out!(
+            format!("🔎 Searching tracks from {sources_text} from now on."),
+            ctx
+        );
+    }
+}
+
+/// Resets the sources to search tracks from back to only Youtube
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reset")]
+pub struct Reset;
+
+impl BotSlashCommand for Reset {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET search_sources = DEFAULT WHERE id = $1;
+            ",
+            ctx.guild_id().get() as i64,
+        )
+        .execute(ctx.db())
+        .await?;
+
+        out!(
+            format!(
+                "🔎 Searching tracks from **{}** from now on.",
+                PlaySource::default().name()
+            ),
+            ctx
+        );
+    }
+}
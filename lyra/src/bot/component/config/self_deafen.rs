@@ -0,0 +1,79 @@
+use sqlx::{Pool, Postgres};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    gateway::payload::outgoing::UpdateVoiceState,
+    id::{marker::GuildMarker, Id},
+};
+
+use crate::bot::{
+    command::{check, macros::out, model::BotSlashCommand, SlashCtx},
+    error::CommandResult,
+    gateway::{ExpectedGuildIdAware, SenderAware},
+    lavalink::LavalinkAware,
+};
+use lyra_proc::BotCommandGroup;
+
+/// Whether the bot deafens itself when joining, as configured in `guild_configs`
+pub async fn enabled(db: &Pool<Postgres>, guild_id: Id<GuildMarker>) -> Result<bool, sqlx::Error> {
+    let self_deaf = sqlx::query!(
+        r"--sql
+        SELECT self_deaf FROM guild_configs WHERE id = $1;",
+        guild_id.get() as i64
+    )
+    .fetch_one(db)
+    .await?
+    .self_deaf;
+    Ok(self_deaf)
+}
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "self-deafen", desc = ".")]
+pub enum SelfDeafen {
+    #[command(name = "toggle")]
+    Toggle(Toggle),
+}
+
+/// Toggles whether the bot should deafen itself in voice channels, which saves bandwidth and assures privacy
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "toggle")]
+pub struct Toggle;
+
+impl BotSlashCommand for Toggle {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let guild_id = ctx.guild_id();
+        let new_self_deaf = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET self_deaf = NOT self_deaf WHERE id = $1 RETURNING self_deaf;
+            ",
+            guild_id.get() as i64,
+        )
+        .fetch_one(ctx.db())
+        .await?
+        .self_deaf;
+
+        let channel_id = ctx
+            .lavalink()
+            .get_connection(guild_id)
+            .map(|c| c.channel_id);
+        if let Some(channel_id) = channel_id {
+            ctx.sender().command(&UpdateVoiceState::new(
+                guild_id,
+                channel_id,
+                new_self_deaf,
+                false,
+            ))?;
+        }
+
+        let (emoji, action) = if new_self_deaf {
+            ("🙉", "Deafening")
+        } else {
+            ("👂", "Not deafening")
+        };
+        out!(
+            format!("{emoji} **{action}** itself in voice channels from now on."),
+            ctx
+        );
+    }
+}
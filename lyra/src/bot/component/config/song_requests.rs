@@ -0,0 +1,149 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::Mention;
+use twilight_model::{
+    application::interaction::InteractionChannel,
+    id::{
+        marker::{ChannelMarker, MessageMarker},
+        Id,
+    },
+};
+
+use crate::bot::{
+    command::{
+        check,
+        macros::{note, out},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    component::queue,
+    core::model::{BotStateAware, HttpAware},
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+    lavalink::LavalinkAware,
+};
+use lyra_proc::BotCommandGroup;
+
+async fn delete_live_message(ctx: &SlashCtx, channel_id: Option<i64>, message_id: Option<i64>) {
+    let (Some(channel_id), Some(message_id)) = (channel_id, message_id) else {
+        return;
+    };
+
+    let channel_id = Id::<ChannelMarker>::new(channel_id as u64);
+    let message_id = Id::<MessageMarker>::new(message_id as u64);
+    if let Err(error) = ctx.http().delete_message(channel_id, message_id).await {
+        tracing::warn!(?error, "deleting song request message failed");
+    }
+}
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "song-requests", desc = ".")]
+pub enum SongRequests {
+    #[command(name = "set")]
+    Set(Set),
+    #[command(name = "reset")]
+    Reset(Reset),
+}
+
+/// Treats every message sent in a text channel as a song request, keeping a pinned queue message there
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "set")]
+pub struct Set {
+    /// Which channel?
+    #[command(channel_types = "guild_text")]
+    channel: InteractionChannel,
+}
+
+impl BotSlashCommand for Set {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let guild_id = ctx.guild_id();
+        let channel_id = self.channel.id;
+
+        let previous = sqlx::query!(
+            r"--sql
+            SELECT song_request_channel, song_request_message FROM guild_configs WHERE id = $1;
+            ",
+            guild_id.get() as i64,
+        )
+        .fetch_one(ctx.db())
+        .await?;
+        delete_live_message(
+            &ctx,
+            previous.song_request_channel,
+            previous.song_request_message,
+        )
+        .await;
+
+        let content = queue::song_request_message_content(ctx.lavalink(), guild_id).await;
+        let message = ctx
+            .http()
+            .create_message(channel_id)
+            .content(&content)
+            .await?
+            .model()
+            .await?;
+        ctx.http().create_pin(channel_id, message.id).await?;
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET song_request_channel = $2, song_request_message = $3 WHERE id = $1;
+            ",
+            guild_id.get() as i64,
+            channel_id.get() as i64,
+            message.id.get() as i64,
+        )
+        .execute(ctx.db())
+        .await?;
+        ctx.bot().song_request_channels().invalidate(guild_id);
+
+        out!(
+            format!(
+                "🎶 Treating every message sent in {} as a song request from now on.",
+                channel_id.mention()
+            ),
+            ctx
+        );
+    }
+}
+
+/// Stops treating messages as song requests, and removes the pinned queue message
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reset")]
+pub struct Reset;
+
+impl BotSlashCommand for Reset {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let guild_id = ctx.guild_id();
+        let previous = sqlx::query!(
+            r"--sql
+            SELECT song_request_channel, song_request_message FROM guild_configs WHERE id = $1;
+            ",
+            guild_id.get() as i64,
+        )
+        .fetch_one(ctx.db())
+        .await?;
+        if previous.song_request_channel.is_none() {
+            note!("No song-request channel is set.", ctx);
+        }
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET song_request_channel = NULL, song_request_message = NULL WHERE id = $1;
+            ",
+            guild_id.get() as i64,
+        )
+        .execute(ctx.db())
+        .await?;
+        ctx.bot().song_request_channels().invalidate(guild_id);
+        delete_live_message(
+            &ctx,
+            previous.song_request_channel,
+            previous.song_request_message,
+        )
+        .await;
+        out!("🎶 Stopped treating messages as song requests.", ctx);
+    }
+}
@@ -0,0 +1,194 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::{
+        check,
+        macros::{bad, note, out},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    component::tuning::{BoundedTuning, TuningFeature},
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+};
+use lyra_proc::BotCommandGroup;
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "tuning", desc = ".")]
+pub enum Tuning {
+    #[command(name = "disable")]
+    Disable(Disable),
+    #[command(name = "enable")]
+    Enable(Enable),
+    #[command(name = "bounds")]
+    Bounds(Bounds),
+    #[command(name = "reset")]
+    Reset(Reset),
+}
+
+/// Disables a tuning feature in this server. Filters it controls can still be turned off.
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "disable")]
+pub struct Disable {
+    /// Which feature?
+    feature: TuningFeature,
+}
+
+impl BotSlashCommand for Disable {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let rows_affected = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET disabled_tuning_features = array_append(disabled_tuning_features, $2)
+            WHERE id = $1 AND NOT ($2 = ANY(disabled_tuning_features));
+            ",
+            ctx.guild_id().get() as i64,
+            self.feature.value(),
+        )
+        .execute(ctx.db())
+        .await?
+        .rows_affected();
+
+        let feature = self.feature.name();
+        if rows_affected == 0 {
+            note!(format!("**`{feature}`** is already disabled."), ctx);
+        }
+        out!(
+            format!("🎛️🔴 Disabled **`{feature}`** in this server."),
+            ctx
+        );
+    }
+}
+
+/// Re-enables a tuning feature in this server
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "enable")]
+pub struct Enable {
+    /// Which feature?
+    feature: TuningFeature,
+}
+
+impl BotSlashCommand for Enable {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let rows_affected = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET disabled_tuning_features = array_remove(disabled_tuning_features, $2)
+            WHERE id = $1 AND $2 = ANY(disabled_tuning_features);
+            ",
+            ctx.guild_id().get() as i64,
+            self.feature.value(),
+        )
+        .execute(ctx.db())
+        .await?
+        .rows_affected();
+
+        let feature = self.feature.name();
+        if rows_affected == 0 {
+            note!(format!("**`{feature}`** is already enabled."), ctx);
+        }
+        out!(format!("🎛️🟢 Enabled **`{feature}`** in this server."), ctx);
+    }
+}
+
+/// Sets the lowest and highest multipliers allowed for speed or pitch
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "bounds")]
+pub struct Bounds {
+    /// Which setting?
+    setting: BoundedTuning,
+    /// Allow down to what multiplier? (If not given, no lower bound)
+    #[command(min_value = 0)]
+    min: Option<f64>,
+    /// Allow up to what multiplier? (If not given, no upper bound)
+    #[command(min_value = 0)]
+    max: Option<f64>,
+}
+
+impl BotSlashCommand for Bounds {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let (min, max) = (self.min, self.max);
+        if min.is_some_and(|m| m <= 0.) || max.is_some_and(|m| m <= 0.) {
+            bad!("Bounds must be greater than `0`×.", ctx);
+        }
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                bad!(
+                    format!("The lower bound `{min}`× must not exceed the upper bound `{max}`×."),
+                    ctx
+                );
+            }
+        }
+
+        let guild_id = ctx.guild_id().get() as i64;
+        match self.setting {
+            BoundedTuning::Speed => {
+                sqlx::query!(
+                    r"--sql
+                    UPDATE guild_configs SET min_speed = $2, max_speed = $3 WHERE id = $1;
+                    ",
+                    guild_id,
+                    min,
+                    max,
+                )
+                .execute(ctx.db())
+                .await?;
+            }
+            BoundedTuning::Pitch => {
+                sqlx::query!(
+                    r"--sql
+                    UPDATE guild_configs SET min_pitch = $2, max_pitch = $3 WHERE id = $1;
+                    ",
+                    guild_id,
+                    min,
+                    max,
+                )
+                .execute(ctx.db())
+                .await?;
+            }
+        }
+
+        let setting = self.setting.name();
+        let bounds = match (min, max) {
+            (None, None) => {
+                out!(format!("🎚️ **`{setting}`** is no longer bounded."), ctx);
+            }
+            (Some(min), Some(max)) => format!("between `{min}`× and `{max}`×"),
+            (Some(min), None) => format!("at least `{min}`×"),
+            (None, Some(max)) => format!("at most `{max}`×"),
+        };
+        out!(format!("🎚️ **`{setting}`** must now be {bounds}."), ctx);
+    }
+}
+
+/// Re-enables every tuning feature and removes all speed and pitch bounds
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reset")]
+pub struct Reset;
+
+impl BotSlashCommand for Reset {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET
+                disabled_tuning_features = DEFAULT,
+                min_speed = NULL,
+                max_speed = NULL,
+                min_pitch = NULL,
+                max_pitch = NULL
+            WHERE id = $1;
+            ",
+            ctx.guild_id().get() as i64,
+        )
+        .execute(ctx.db())
+        .await?;
+
+        out!("🎛️ Reset this server's tuning restrictions.", ctx);
+    }
+}
@@ -0,0 +1,109 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::Mention;
+
+use crate::bot::{
+    command::{
+        check,
+        macros::{caut, note, out},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    component::connection,
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+    lavalink::{LavalinkAware, PlayerDataAware},
+};
+use lyra_proc::BotCommandGroup;
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "24-7", desc = ".")]
+pub enum TwentyFourSeven {
+    #[command(name = "on")]
+    On(On),
+    #[command(name = "off")]
+    Off(Off),
+}
+
+/// Stays in the current voice channel regardless of inactivity, rejoining it after restarts
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "on")]
+pub struct On;
+
+impl BotSlashCommand for On {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let guild_id = ctx.guild_id();
+        let Some((channel_id, text_channel_id)) = ctx
+            .lavalink()
+            .get_connection(guild_id)
+            .map(|c| (c.channel_id, c.text_channel_id))
+        else {
+            caut!(
+                "Not currently connected to a voice channel. Join one first, then turn 24/7 mode on.",
+                ctx
+            );
+        };
+
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs
+            SET
+                twenty_four_seven = true,
+                twenty_four_seven_channel = $2,
+                twenty_four_seven_text_channel = $3
+            WHERE id = $1;
+            ",
+            guild_id.get() as i64,
+            channel_id.get() as i64,
+            text_channel_id.get() as i64,
+        )
+        .execute(ctx.db())
+        .await?;
+        if let Some(data) = ctx.get_player_data() {
+            connection::save_twenty_four_seven_queue(ctx.db(), guild_id, &data).await?;
+        }
+
+        out!(
+            format!(
+                "🔁 Staying in {} 24/7 from now on, and rejoining it after restarts.",
+                channel_id.mention()
+            ),
+            ctx
+        );
+    }
+}
+
+/// Leaves due to inactivity again, and stops rejoining after restarts
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "off")]
+pub struct Off;
+
+impl BotSlashCommand for Off {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::user_is_access_manager(&ctx)?;
+
+        let rows_affected = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs
+            SET
+                twenty_four_seven = false,
+                twenty_four_seven_channel = NULL,
+                twenty_four_seven_text_channel = NULL,
+                twenty_four_seven_queue = DEFAULT,
+                twenty_four_seven_requesters = DEFAULT,
+                twenty_four_seven_queue_index = DEFAULT
+            WHERE id = $1 AND twenty_four_seven;
+            ",
+            ctx.guild_id().get() as i64,
+        )
+        .execute(ctx.db())
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            note!("24/7 mode is already off.", ctx);
+        }
+        out!("🔁 Turned 24/7 mode off.", ctx);
+    }
+}
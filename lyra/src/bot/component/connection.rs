@@ -1,12 +1,24 @@
+mod channel_status;
+mod deletion;
+mod follow;
 mod join;
 mod leave;
+mod reconnect;
+mod twenty_four_seven;
 
+pub use channel_status::update as update_channel_status;
+pub use deletion::{handle_channel_deletion, handle_guild_removal};
 pub use join::{auto as auto_join, Join};
 pub use leave::Leave;
+pub use reconnect::{connect as connect_without_interaction, rejoin_after_network_drop};
+pub use twenty_four_seven::{
+    rejoin as rejoin_twenty_four_seven, save_queue as save_twenty_four_seven_queue,
+};
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use chrono::Utc;
+use sqlx::{Pool, Postgres};
 use twilight_cache_inmemory::{model::CachedVoiceState, InMemoryCache};
 use twilight_gateway::MessageSender;
 use twilight_http::Client;
@@ -21,22 +33,71 @@ use crate::bot::{
     component::connection::{
         join::JoinedChannelType,
         leave::{disconnect, pre_disconnect_cleanup, LeaveResponse},
+        reconnect::SavedQueue,
     },
     core::{
-        model::{BotState, BotStateAware, CacheAware, HttpAware, OwnedBotStateAware},
+        model::{
+            BotState, BotStateAware, CacheAware, HttpAware, OwnedBotState, OwnedBotStateAware,
+        },
         r#const::{connection as const_connection, exit_code::NOTICE},
         traced,
     },
     error::{
         self,
         component::connection::{
-            HandleVoiceStateUpdateError, MatchStateChannelIdError, StartInactivityTimeoutError,
+            HandleVoiceStateUpdateError, MatchStateChannelIdError, PauseWhenAloneError,
+            StartInactivityTimeoutError,
         },
     },
     gateway::{voice, ExpectedGuildIdAware, SenderAware},
-    lavalink::{self, LavalinkAware},
+    lavalink::{self, DelegateMethods, LavalinkAware},
 };
 
+/// How a guild's voice connections behave when no one is listening, as configured in `guild_configs`
+pub(super) struct InactivitySettings {
+    timeout: Duration,
+    pause_when_alone: bool,
+    queue_end_timeout: Option<Duration>,
+    twenty_four_seven: bool,
+}
+
+impl InactivitySettings {
+    pub(super) async fn fetch(
+        db: &Pool<Postgres>,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Self, sqlx::Error> {
+        let settings = sqlx::query!(
+            r"--sql
+            SELECT inactivity_timeout_secs, pause_when_alone, queue_end_timeout_mins, twenty_four_seven FROM guild_configs WHERE id = $1;",
+            guild_id.get() as i64
+        )
+        .fetch_one(db)
+        .await?;
+
+        Ok(Self {
+            timeout: Duration::from_secs(settings.inactivity_timeout_secs as u64),
+            pause_when_alone: settings.pause_when_alone,
+            queue_end_timeout: (settings.queue_end_timeout_mins != 0)
+                .then(|| Duration::from_secs(settings.queue_end_timeout_mins as u64 * 60)),
+            twenty_four_seven: settings.twenty_four_seven,
+        })
+    }
+
+    pub(super) const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Whether the bot should stay connected regardless of inactivity, as 24/7 mode is on
+    pub(super) const fn stays_connected(&self) -> bool {
+        self.twenty_four_seven
+    }
+
+    /// When the bot will disconnect if no one joins in from now, as a unix timestamp
+    pub(super) fn disconnect_timestamp(&self) -> i64 {
+        Utc::now().timestamp() + self.timeout.as_secs() as i64
+    }
+}
+
 fn users_in_voice(ctx: &impl CacheAware, channel_id: Id<ChannelMarker>) -> Option<usize> {
     ctx.cache()
         .voice_channel_states(channel_id)
@@ -56,6 +117,14 @@ struct InactivityTimeoutContext {
 }
 
 impl InactivityTimeoutContext {
+    const fn new(inner: Arc<BotState>, sender: MessageSender, guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            inner,
+            sender,
+            guild_id,
+        }
+    }
+
     fn new_via(ctx: &(impl OwnedBotStateAware + SenderAware + ExpectedGuildIdAware)) -> Self {
         Self {
             inner: ctx.bot_owned(),
@@ -95,40 +164,184 @@ impl ExpectedGuildIdAware for InactivityTimeoutContext {
     }
 }
 
+async fn leave_inactive(
+    ctx: &InactivityTimeoutContext,
+    channel_id: Id<ChannelMarker>,
+    text_channel_id: Id<ChannelMarker>,
+    reason: &str,
+) -> Result<(), StartInactivityTimeoutError> {
+    let guild_id = ctx.guild_id;
+    ctx.lavalink().notify_connection_change(guild_id);
+    pre_disconnect_cleanup(ctx).await?;
+    disconnect(ctx)?;
+
+    let response = LeaveResponse(channel_id);
+
+    tracing::debug!("guild {} {} {}", guild_id, response, reason);
+    ctx.http()
+        .create_message(text_channel_id)
+        .content(&format!(
+            "💤📎 ~~{}~~ `(Left {})`",
+            channel_id.mention(),
+            reason
+        ))
+        .await?;
+
+    Ok(())
+}
+
 async fn start_inactivity_timeout(
     ctx: InactivityTimeoutContext,
     channel_id: Id<ChannelMarker>,
     text_channel_id: Id<ChannelMarker>,
+    timeout: Duration,
 ) -> Result<(), StartInactivityTimeoutError> {
-    let guild_id = ctx.guild_id;
     tracing::debug!(
         "guild {} started channel {} inactivity timeout",
-        guild_id,
+        ctx.guild_id,
         channel_id
     );
 
-    for _ in 0..const_connection::INACTIVITY_TIMEOUT_POLL_N {
-        tokio::time::sleep(*const_connection::INACTIVITY_TIMEOUT_POLL_INTERVAL).await;
+    let poll_interval = timeout / const_connection::INACTIVITY_TIMEOUT_POLL_N;
+    let mut polls = 0;
+    while polls < const_connection::INACTIVITY_TIMEOUT_POLL_N {
+        tokio::time::sleep(poll_interval).await;
         if users_in_voice(&ctx, channel_id).is_some_and(|n| n >= 1) {
             return Ok(());
         }
+
+        // the timeout is suspended while the bot is server muted
+        let muted = ctx
+            .lavalink()
+            .get_connection(ctx.guild_id)
+            .is_some_and(|c| c.mute);
+        if !muted {
+            polls += 1;
+        }
     }
 
-    ctx.lavalink().notify_connection_change(guild_id);
-    pre_disconnect_cleanup(&ctx).await?;
-    disconnect(&ctx)?;
+    leave_inactive(&ctx, channel_id, text_channel_id, "due to inactivity").await
+}
 
-    let response = LeaveResponse(channel_id);
+async fn leave_after_queue_end(
+    ctx: InactivityTimeoutContext,
+    timeout: Duration,
+) -> Result<(), StartInactivityTimeoutError> {
+    tokio::time::sleep(timeout).await;
 
-    tracing::debug!("guild {} {} due to inactivity", guild_id, response);
+    let guild_id = ctx.guild_id;
+    let Some(data) = ctx.lavalink().get_player_data(guild_id) else {
+        return Ok(());
+    };
+    let mut data_w = data.write().await;
+    data_w.forget_queue_end_timeout();
+    if data_w.queue().current().is_some() {
+        return Ok(());
+    }
+    drop(data_w);
+
+    let Some((channel_id, text_channel_id)) = ctx
+        .lavalink()
+        .get_connection(guild_id)
+        .map(|c| (c.channel_id, c.text_channel_id))
+    else {
+        return Ok(());
+    };
+    leave_inactive(&ctx, channel_id, text_channel_id, "as the queue has ended").await
+}
+
+/// Leaves once the queue has stayed ended for as long as the guild configured, unless a track starts before then
+pub async fn start_queue_end_timeout(
+    bot: OwnedBotState,
+    guild_id: Id<GuildMarker>,
+) -> Result<(), sqlx::Error> {
+    let settings = InactivitySettings::fetch(bot.db(), guild_id).await?;
+    let Some(timeout) = settings.queue_end_timeout else {
+        return Ok(());
+    };
+    if settings.stays_connected() {
+        return Ok(());
+    }
+    let Some(sender) = bot
+        .lavalink()
+        .get_connection(guild_id)
+        .map(|c| c.sender().clone())
+    else {
+        return Ok(());
+    };
+    let Some(data) = bot.lavalink().get_player_data(guild_id) else {
+        return Ok(());
+    };
+
+    tracing::debug!("guild {} started queue end timeout", guild_id);
+    let ctx = InactivityTimeoutContext::new(bot, sender, guild_id);
+    let handle = traced::tokio_spawn(leave_after_queue_end(ctx, timeout));
+    data.write()
+        .await
+        .set_queue_end_timeout(handle.abort_handle());
+    Ok(())
+}
+
+async fn pause_when_alone(
+    ctx: &(impl LavalinkAware + HttpAware + ExpectedGuildIdAware),
+    text_channel_id: Id<ChannelMarker>,
+) -> Result<(), PauseWhenAloneError> {
+    let guild_id = ctx.guild_id();
+    let (Some(player), Some(data)) = (
+        ctx.lavalink().get_player_context(guild_id),
+        ctx.lavalink().get_player_data(guild_id),
+    ) else {
+        return Ok(());
+    };
+    let state = player.get_player().await?;
+    if state.track.is_none() || state.paused {
+        return Ok(());
+    }
+
+    player.set_pause(true).await?;
+    data.write().await.set_paused_when_alone();
+
+    tracing::debug!("guild {} paused as everyone left", guild_id);
     ctx.http()
         .create_message(text_channel_id)
-        .content(&format!(
-            "💤📎 ~~{}~~ `(Left due to inactivity)`",
-            channel_id.mention()
-        ))
+        .content("⏸️ `(Paused as everyone left)`")
         .await?;
+    Ok(())
+}
 
+async fn resume_on_return(
+    ctx: &(impl LavalinkAware + HttpAware + ExpectedGuildIdAware),
+    text_channel_id: Id<ChannelMarker>,
+) -> Result<(), PauseWhenAloneError> {
+    let guild_id = ctx.guild_id();
+    let (Some(player), Some(data)) = (
+        ctx.lavalink().get_player_context(guild_id),
+        ctx.lavalink().get_player_data(guild_id),
+    ) else {
+        return Ok(());
+    };
+    let muted = ctx
+        .lavalink()
+        .get_connection(guild_id)
+        .is_some_and(|c| c.mute);
+    let mut data_w = data.write().await;
+    if !data_w.take_paused_when_alone() {
+        return Ok(());
+    }
+    if muted {
+        // still server muted, so stay paused until unmuted instead
+        data_w.set_paused_when_muted();
+        return Ok(());
+    }
+    drop(data_w);
+
+    player.set_pause(false).await?;
+
+    tracing::debug!("guild {} resumed as someone returned", guild_id);
+    ctx.http()
+        .create_message(text_channel_id)
+        .content("▶️ `(Resumed as someone returned)`")
+        .await?;
     Ok(())
 }
 
@@ -143,6 +356,14 @@ pub async fn handle_voice_state_update(
     let lavalink = ctx.lavalink();
 
     tracing::trace!("handling voice state update");
+    if state.user_id == ctx.bot().user_id()
+        && maybe_old_state.is_some_and(|s| Some(s.channel_id()) == state.channel_id)
+    {
+        // the bot stayed in the same channel, so only something like being deafened or becoming a speaker changed
+        tracing::trace!("voice state changed in place");
+        return Ok(());
+    }
+
     let (connected_channel_id, text_channel_id) = {
         let Some(connection) = lavalink.get_connection(guild_id) else {
             return Ok(());
@@ -157,37 +378,52 @@ pub async fn handle_voice_state_update(
         (connection.channel_id, connection.text_channel_id)
     };
 
-    match maybe_old_state {
-        Some(old_state) if state.user_id != ctx.bot().user_id() => {
-            let old_channel_id = old_state.channel_id();
-            if old_channel_id == connected_channel_id
-                && state.channel_id != Some(old_channel_id)
-                && users_in_voice(ctx, connected_channel_id).is_some_and(|n| n == 0)
-            {
+    if state.user_id != ctx.bot().user_id() {
+        let old_channel_id = maybe_old_state.map(CachedVoiceState::channel_id);
+        if old_channel_id == Some(connected_channel_id)
+            && follow::follow(ctx, connected_channel_id, text_channel_id).await?
+        {
+            return Ok(());
+        }
+
+        if old_channel_id == Some(connected_channel_id)
+            && state.channel_id != Some(connected_channel_id)
+            && users_in_voice(ctx, connected_channel_id).is_some_and(|n| n == 0)
+        {
+            let settings = InactivitySettings::fetch(ctx.bot().db(), guild_id).await?;
+            if settings.pause_when_alone {
+                pause_when_alone(ctx, text_channel_id).await?;
+            }
+            if !settings.stays_connected() {
                 traced::tokio_spawn(start_inactivity_timeout(
                     InactivityTimeoutContext::new_via(ctx),
                     connected_channel_id,
                     text_channel_id,
+                    settings.timeout,
                 ));
             }
-            return Ok(());
+        } else if state.channel_id == Some(connected_channel_id)
+            && old_channel_id != Some(connected_channel_id)
+        {
+            resume_on_return(ctx, text_channel_id).await?;
         }
+        return Ok(());
+    }
+
+    match maybe_old_state {
         Some(old_state) if state.channel_id.is_none() => {
+            let grace = reconnect::reconnect_grace(ctx.bot().db(), guild_id).await?;
+            let queue = match grace {
+                Some(_) => SavedQueue::take(lavalink, guild_id).await?,
+                None => None,
+            };
             pre_disconnect_cleanup(ctx).await?;
 
             let old_channel_id = old_state.channel_id();
             let response = LeaveResponse(old_channel_id);
 
             tracing::warn!("guild {} {} forcefully", guild_id, response);
-            ctx.bot()
-                .http()
-                .create_message(text_channel_id)
-                .content(&format!(
-                    "{}📎 ~~{}~~ `(Bot was forcefully disconnected)`",
-                    NOTICE,
-                    old_channel_id.mention()
-                ))
-                .await?;
+            reconnect::offer_reconnect(ctx, old_channel_id, text_channel_id, queue, grace).await?;
         }
         Some(old_state) => {
             match_state_channel_id(state.channel_id, old_state, guild_id, text_channel_id, ctx)
@@ -215,6 +451,11 @@ async fn match_state_channel_id(
             );
 
             let voice_is_empty = users_in_voice(ctx, channel_id).is_some_and(|n| n == 0);
+            if let Err(error) = channel_status::restore(ctx, old_channel_id).await {
+                tracing::warn!(?error, "restoring channel status failed");
+            }
+            ctx.lavalink().connection_mut(guild_id).channel_id = channel_id;
+            let settings = InactivitySettings::fetch(ctx.bot().db(), guild_id).await?;
 
             let response = join::Response::Moved {
                 from: old_channel_id,
@@ -222,10 +463,12 @@ async fn match_state_channel_id(
                 empty: voice_is_empty,
             };
 
-            let forcefully_moved_notice = if voice_is_empty {
+            let forcefully_moved_notice = if voice_is_empty && settings.stays_connected() {
+                "`(Bot was forcefully moved to an empty voice channel)`".into()
+            } else if voice_is_empty {
                 format!(
                     "\n`(Bot was forcefully moved to an empty voice channel, and automatically disconnecting if no one else joins in` <t:{}:R> `)`",
-                    Utc::now().timestamp() + i64::from(const_connection::INACTIVITY_TIMEOUT_SECS)
+                    settings.disconnect_timestamp()
                 )
             } else {
                 "`(Bot was forcefully moved)`".into()
@@ -260,11 +503,17 @@ async fn match_state_channel_id(
             }
 
             if voice_is_empty {
-                traced::tokio_spawn(start_inactivity_timeout(
-                    InactivityTimeoutContext::new_via(ctx),
-                    channel_id,
-                    text_channel_id,
-                ));
+                if settings.pause_when_alone {
+                    pause_when_alone(ctx, text_channel_id).await?;
+                }
+                if !settings.stays_connected() {
+                    traced::tokio_spawn(start_inactivity_timeout(
+                        InactivityTimeoutContext::new_via(ctx),
+                        channel_id,
+                        text_channel_id,
+                        settings.timeout,
+                    ));
+                }
             };
             Ok(())
         }
@@ -0,0 +1,179 @@
+use lavalink_rs::model::track::TrackData;
+use serde::Deserialize;
+use twilight_http::{
+    request::{Method, RequestBuilder},
+    response::marker::EmptyBody,
+    routing::Path,
+    Client,
+};
+use twilight_model::{
+    channel::ChannelType,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+
+use crate::bot::{
+    core::{
+        model::{BotState, CacheAware, HttpAware},
+        r#const::discord::{STAGE_TOPIC_LENGTH_LIMIT, VOICE_CHANNEL_STATUS_LENGTH_LIMIT},
+    },
+    error::component::connection::{RestoreChannelStatusError, UpdateChannelStatusError},
+    ext::util::PrettyTruncator,
+    gateway::ExpectedGuildIdAware,
+    lavalink::{ChannelStatus, CorrectTrackInfo, LavalinkAware},
+};
+
+/// The part of a voice channel that twilight's channel model leaves out
+#[derive(Deserialize)]
+struct VoiceChannel {
+    status: Option<String>,
+}
+
+async fn voice_status(
+    http: &Client,
+    channel_id: Id<ChannelMarker>,
+) -> Result<Option<String>, UpdateChannelStatusError> {
+    let bytes = http.channel(channel_id).await?.bytes().await?;
+    let channel = serde_json::from_slice::<VoiceChannel>(&bytes)?;
+    Ok(channel.status.filter(|s| !s.is_empty()))
+}
+
+/// Sets the voice channel status through twilight's ratelimiter, which has no endpoint for it and so shares the channel's bucket
+async fn set_voice_status(
+    http: &Client,
+    channel_id: Id<ChannelMarker>,
+    status: &str,
+) -> Result<(), twilight_http::Error> {
+    let request = RequestBuilder::raw(
+        Method::Put,
+        Path::ChannelsId(channel_id.get()),
+        format!("channels/{channel_id}/voice-status"),
+    )
+    .json(&serde_json::json!({ "status": status }))
+    .build()?;
+
+    http.request::<EmptyBody>(request).await?;
+    Ok(())
+}
+
+fn stage_topic(
+    bot: &BotState,
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+) -> Option<String> {
+    let cache = bot.cache();
+    cache
+        .guild_stage_instances(guild_id)?
+        .iter()
+        .find_map(|stage_id| {
+            cache
+                .stage_instance(*stage_id)
+                .filter(|s| s.channel_id == channel_id)
+                .map(|s| s.topic.clone())
+        })
+}
+
+/// Shows the track that just started in the stage topic or the voice channel status, if turned on for the guild
+pub async fn update(
+    bot: &BotState,
+    guild_id: Id<GuildMarker>,
+    track: &TrackData,
+) -> Result<(), UpdateChannelStatusError> {
+    let enabled = sqlx::query!(
+        r"--sql
+        SELECT channel_status FROM guild_configs WHERE id = $1;",
+        guild_id.get() as i64
+    )
+    .fetch_one(bot.db())
+    .await?
+    .channel_status;
+    if !enabled {
+        return Ok(());
+    }
+
+    let Some((channel_id, recorded)) = bot
+        .lavalink()
+        .get_connection(guild_id)
+        .map(|c| (c.channel_id, c.channel_status().is_some()))
+    else {
+        return Ok(());
+    };
+    let text = format!(
+        "♪ {} — {}",
+        track.info.corrected_title(),
+        track.info.corrected_author()
+    );
+
+    match bot.cache().channel(channel_id).map(|c| c.kind) {
+        Some(ChannelType::GuildStageVoice) => {
+            let topic = text.pretty_truncate(STAGE_TOPIC_LENGTH_LIMIT);
+            let previous = stage_topic(bot, guild_id, channel_id);
+            if previous.is_some() {
+                bot.http()
+                    .update_stage_instance(channel_id)
+                    .topic(&topic)
+                    .await?;
+            } else {
+                bot.http().create_stage_instance(channel_id, &topic).await?;
+            }
+
+            if !recorded {
+                if let Some(mut connection) = bot.lavalink().get_connection_mut(guild_id) {
+                    connection.set_channel_status(ChannelStatus::StageTopic { previous });
+                }
+            }
+        }
+        Some(ChannelType::GuildVoice) => {
+            let previous = if recorded {
+                None
+            } else {
+                voice_status(bot.http(), channel_id).await?
+            };
+            let status = text.pretty_truncate(VOICE_CHANNEL_STATUS_LENGTH_LIMIT);
+            set_voice_status(bot.http(), channel_id, &status).await?;
+
+            if !recorded {
+                if let Some(mut connection) = bot.lavalink().get_connection_mut(guild_id) {
+                    connection.set_channel_status(ChannelStatus::VoiceStatus { previous });
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Puts back the stage topic or voice channel status from before the bot changed it
+pub(super) async fn restore(
+    ctx: &(impl HttpAware + LavalinkAware + ExpectedGuildIdAware),
+    channel_id: Id<ChannelMarker>,
+) -> Result<(), RestoreChannelStatusError> {
+    let Some(status) = ctx
+        .lavalink()
+        .get_connection_mut(ctx.guild_id())
+        .and_then(|mut c| c.take_channel_status())
+    else {
+        return Ok(());
+    };
+
+    match status {
+        ChannelStatus::StageTopic {
+            previous: Some(previous),
+        } => {
+            ctx.http()
+                .update_stage_instance(channel_id)
+                .topic(&previous)
+                .await?;
+        }
+        ChannelStatus::StageTopic { previous: None } => {
+            ctx.http().delete_stage_instance(channel_id).await?;
+        }
+        ChannelStatus::VoiceStatus { previous } => {
+            let previous = previous.unwrap_or_default();
+            set_voice_status(ctx.http(), channel_id, &previous).await?;
+        }
+    }
+    Ok(())
+}
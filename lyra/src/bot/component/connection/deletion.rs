@@ -0,0 +1,80 @@
+use twilight_mention::Mention;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+use crate::bot::{
+    core::model::{HttpAware, OwnedBotState},
+    error::component::connection::{leave::PreDisconnectCleanupError, HandleChannelDeletionError},
+    lavalink::LavalinkAware,
+};
+
+use super::{
+    leave::{disconnect, pre_disconnect_cleanup, LeaveResponse},
+    InactivityTimeoutContext,
+};
+
+/// Destroys the player and forgets the connection of a guild the bot has been removed from
+pub async fn handle_guild_removal(
+    bot: OwnedBotState,
+    guild_id: Id<GuildMarker>,
+) -> Result<(), PreDisconnectCleanupError> {
+    let Some(sender) = bot
+        .lavalink()
+        .get_connection(guild_id)
+        .map(|c| c.sender().clone())
+    else {
+        return Ok(());
+    };
+
+    let ctx = InactivityTimeoutContext::new(bot, sender, guild_id);
+    ctx.lavalink().notify_connection_change(guild_id);
+    pre_disconnect_cleanup(&ctx).await?;
+
+    tracing::debug!("guild {} removed the bot while connected", guild_id);
+    Ok(())
+}
+
+/// Leaves when the connected voice channel, or the text channel receiving notices, has been deleted
+pub async fn handle_channel_deletion(
+    bot: OwnedBotState,
+    guild_id: Id<GuildMarker>,
+    deleted_channel_id: Id<ChannelMarker>,
+) -> Result<(), HandleChannelDeletionError> {
+    let Some((channel_id, text_channel_id, sender)) = bot
+        .lavalink()
+        .get_connection(guild_id)
+        .map(|c| (c.channel_id, c.text_channel_id, c.sender().clone()))
+    else {
+        return Ok(());
+    };
+    if deleted_channel_id != channel_id && deleted_channel_id != text_channel_id {
+        return Ok(());
+    }
+
+    let ctx = InactivityTimeoutContext::new(bot, sender, guild_id);
+    ctx.lavalink().notify_connection_change(guild_id);
+    pre_disconnect_cleanup(&ctx).await?;
+    disconnect(&ctx)?;
+
+    let response = LeaveResponse(channel_id);
+    if deleted_channel_id == text_channel_id {
+        tracing::debug!(
+            "guild {} {} as the text channel was deleted",
+            guild_id,
+            response
+        );
+        return Ok(());
+    }
+
+    tracing::debug!("guild {} {} as it was deleted", guild_id, response);
+    ctx.http()
+        .create_message(text_channel_id)
+        .content(&format!(
+            "📎 ~~{}~~ `(Left as the channel was deleted)`",
+            channel_id.mention()
+        ))
+        .await?;
+    Ok(())
+}
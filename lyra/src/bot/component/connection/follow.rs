@@ -0,0 +1,168 @@
+use twilight_cache_inmemory::InMemoryCache;
+use twilight_gateway::MessageSender;
+use twilight_http::Client;
+use twilight_mention::Mention;
+use twilight_model::{
+    guild::Permissions,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+
+use crate::bot::{
+    component::config::follow::FollowTarget,
+    core::{
+        model::{
+            AuthorPermissionsAware, BotState, BotStateAware, CacheAware, HttpAware, OwnedBotState,
+            OwnedBotStateAware,
+        },
+        r#const::exit_code::NOTICE,
+    },
+    error::{
+        command::check::UserAllowedError,
+        component::connection::{join::FollowError, FollowUserError},
+    },
+    gateway::{voice, ExpectedGuildIdAware, SenderAware},
+    lavalink::{DelegateMethods, Lavalink, LavalinkAware, QueueItem},
+};
+
+use super::{
+    channel_status,
+    join::{self, JoinedChannelType},
+};
+
+/// Acts on behalf of the followed user, so that moving along with them goes through the same checks as `/join`
+struct FollowContext {
+    bot: OwnedBotState,
+    sender: MessageSender,
+    guild_id: Id<GuildMarker>,
+    user_permissions: Permissions,
+}
+
+impl AuthorPermissionsAware for FollowContext {
+    fn author_permissions(&self) -> Permissions {
+        self.user_permissions
+    }
+}
+
+impl BotStateAware for FollowContext {
+    fn bot(&self) -> &BotState {
+        &self.bot
+    }
+}
+
+impl CacheAware for FollowContext {
+    fn cache(&self) -> &InMemoryCache {
+        self.bot.cache()
+    }
+}
+
+impl HttpAware for FollowContext {
+    fn http(&self) -> &Client {
+        self.bot.http()
+    }
+}
+
+impl LavalinkAware for FollowContext {
+    fn lavalink(&self) -> &Lavalink {
+        self.bot.lavalink()
+    }
+}
+
+impl SenderAware for FollowContext {
+    fn sender(&self) -> &MessageSender {
+        &self.sender
+    }
+}
+
+impl ExpectedGuildIdAware for FollowContext {
+    fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+}
+
+async fn is_followed(ctx: &voice::Context) -> Result<bool, sqlx::Error> {
+    let guild_id = ctx.guild_id();
+    let user_id = ctx.inner.user_id;
+    let followed = match FollowTarget::fetch(ctx.bot().db(), guild_id).await? {
+        Some(FollowTarget::User(followed_id)) => followed_id == user_id,
+        Some(FollowTarget::Dj) => match ctx.lavalink().get_player_data(guild_id) {
+            Some(data) => {
+                data.read()
+                    .await
+                    .queue()
+                    .current()
+                    .map(QueueItem::requester)
+                    == Some(user_id)
+            }
+            None => false,
+        },
+        None => false,
+    };
+    Ok(followed)
+}
+
+/// Moves along with the followed user after they left the connected channel for another, returning whether the bot moved
+pub(super) async fn follow(
+    ctx: &voice::Context,
+    connected_channel_id: Id<ChannelMarker>,
+    text_channel_id: Id<ChannelMarker>,
+) -> Result<bool, FollowUserError> {
+    let Some(channel_id) = ctx
+        .inner
+        .channel_id
+        .filter(|channel_id| *channel_id != connected_channel_id)
+    else {
+        return Ok(false);
+    };
+    if !is_followed(ctx).await? {
+        return Ok(false);
+    }
+
+    let user_id = ctx.inner.user_id;
+    let follow_ctx = FollowContext {
+        bot: ctx.bot_owned(),
+        sender: ctx.sender().clone(),
+        guild_id: ctx.guild_id(),
+        user_permissions: ctx.cache().permissions().in_channel(user_id, channel_id)?,
+    };
+    let joined = match join::follow(channel_id, &follow_ctx).await {
+        Ok(joined) => joined,
+        Err(
+            FollowError::UserNotStageManager(_)
+            | FollowError::Forbidden(_)
+            | FollowError::CheckUserAllowed(UserAllowedError::UserNotAllowed(_)),
+        ) => {
+            ctx.http()
+                .create_message(text_channel_id)
+                .content(&format!(
+                    "{NOTICE} Couldn't follow {} into {}.",
+                    user_id.mention(),
+                    channel_id.mention()
+                ))
+                .await?;
+            return Ok(false);
+        }
+        Err(e) => Err(e)?,
+    };
+
+    if let Err(error) = channel_status::restore(ctx, connected_channel_id).await {
+        tracing::warn!(?error, "restoring channel status failed");
+    }
+    let stage_emoji = match joined.kind {
+        JoinedChannelType::Stage => "🎭",
+        JoinedChannelType::Voice => "",
+    };
+    ctx.http()
+        .create_message(text_channel_id)
+        .content(&format!(
+            "👣{}📎🖇️ ~~{}~~ ➜ __{}__ `(Followed` {}`)`",
+            stage_emoji,
+            connected_channel_id.mention(),
+            channel_id.mention(),
+            user_id.mention()
+        ))
+        .await?;
+    Ok(true)
+}
@@ -22,18 +22,23 @@ use crate::bot::{
         model::{BotSlashCommand, Ctx, CtxKind, RespondViaMessage},
         SlashCtx,
     },
-    component::connection::{start_inactivity_timeout, users_in_voice},
+    component::{
+        config::{music_channel::MusicChannelSettings, self_deafen},
+        connection::{start_inactivity_timeout, users_in_voice, InactivitySettings},
+    },
     core::{
-        model::{BotState, BotStateAware, CacheAware, HttpAware, OwnedBotStateAware},
-        r#const::connection::INACTIVITY_TIMEOUT_SECS,
+        model::{
+            AuthorPermissionsAware, BotState, BotStateAware, CacheAware, HttpAware,
+            OwnedBotStateAware,
+        },
         traced,
     },
     error::{
         self,
         component::connection::join::{
             AutoJoinError, ConnectToError, ConnectToNewError, DeleteEmptyVoiceNoticeError,
-            Error as JoinError, GetUsersVoiceChannelError, HandleResponseError, ImplAutoJoinError,
-            ImplConnectToError, ImplJoinError, Pfe,
+            Error as JoinError, FollowError, GetUsersVoiceChannelError, HandleResponseError,
+            ImplAutoJoinError, ImplConnectToError, ImplJoinError, Pfe,
         },
         Cache as CacheError, CommandResult, UserNotInVoice as UserNotInVoiceError,
     },
@@ -131,7 +136,7 @@ async fn impl_auto_join(ctx: &Ctx<impl CtxKind>) -> Result<Response, ImplAutoJoi
 
 fn check_user_is_stage_manager(
     channel_type: ChannelType,
-    ctx: &Ctx<impl CtxKind>,
+    ctx: &impl AuthorPermissionsAware,
 ) -> Result<(), error::UserNotStageManager> {
     if channel_type == ChannelType::GuildStageVoice {
         check::user_is_stage_manager(ctx)?;
@@ -214,8 +219,12 @@ async fn impl_connect_to(
 
     let response = old_channel_id.map_or_else(
         || {
-            ctx.lavalink()
-                .new_connection(guild_id, channel_id, ctx.channel_id());
+            ctx.lavalink().new_connection(
+                guild_id,
+                channel_id,
+                ctx.channel_id(),
+                ctx.sender().clone(),
+            );
             Response::Joined {
                 voice: joined,
                 empty: voice_is_empty,
@@ -231,21 +240,68 @@ async fn impl_connect_to(
         },
     );
 
+    request_voice_state(joined, guild_id, ctx).await?;
+
+    tracing::debug!("guild {guild_id} {response}");
+    Ok(response)
+}
+
+async fn request_voice_state(
+    joined: JoinedChannel,
+    guild_id: Id<GuildMarker>,
+    ctx: &(impl BotStateAware + LavalinkAware + SenderAware + HttpAware),
+) -> Result<(), ImplConnectToError> {
+    let self_deaf = self_deafen::enabled(ctx.bot().db(), guild_id).await?;
     ctx.lavalink().notify_connection_change(guild_id);
-    ctx.sender()
-        .command(&UpdateVoiceState::new(guild_id, channel_id, true, false))?;
+    ctx.sender().command(&UpdateVoiceState::new(
+        guild_id, joined.id, self_deaf, false,
+    ))?;
 
     if joined.kind == JoinedChannelType::Stage {
-        ctx.bot()
-            .http()
+        ctx.http()
             .update_current_user_voice_state(guild_id)
-            .channel_id(channel_id)
+            .channel_id(joined.id)
             .request_to_speak_timestamp(&Utc::now().to_rfc3339())
             .await?;
     }
+    Ok(())
+}
 
-    tracing::debug!("guild {guild_id} {response}");
-    Ok(response)
+/// Moves to where a followed user has moved to, as long as they could have used `/join` there themselves
+pub(super) async fn follow(
+    channel_id: Id<ChannelMarker>,
+    ctx: &(impl AuthorPermissionsAware
+          + BotStateAware
+          + CacheAware
+          + HttpAware
+          + LavalinkAware
+          + SenderAware
+          + ExpectedGuildIdAware),
+) -> Result<JoinedChannel, FollowError> {
+    let guild_id = ctx.guild_id();
+    let (channel_type, channel_parent_id) = ctx
+        .cache()
+        .channel(channel_id)
+        .map(|c| (c.kind, c.parent_id))
+        .ok_or(CacheError)?;
+    check_user_is_stage_manager(channel_type, ctx)?;
+
+    let bot_permissions = ctx
+        .cache()
+        .permissions()
+        .in_channel(ctx.bot().user_id(), channel_id)?;
+    if !bot_permissions.contains(Permissions::CONNECT) {
+        Err(error::ConnectionForbidden(channel_id))?;
+    }
+    check::user_allowed_to_use(channel_id, channel_parent_id, ctx).await?;
+
+    let joined = JoinedChannel::new(channel_id, channel_type);
+    let from = ctx.lavalink().connection(guild_id).channel_id;
+    ctx.lavalink().connection_mut(guild_id).channel_id = channel_id;
+    request_voice_state(joined, guild_id, ctx).await?;
+
+    tracing::debug!("guild {} followed {} -> {}", guild_id, from, joined);
+    Ok(joined)
 }
 
 struct DeleteEmptyVoiceNotice {
@@ -327,17 +383,26 @@ async fn handle_response(
         }
     };
 
-    if empty {
-        let text_channel_id = ctx.channel_id();
+    let guild_id = ctx.guild_id();
+    let text_channel_id = MusicChannelSettings::get(ctx.bot(), guild_id)
+        .await?
+        .map_or(ctx.channel_id(), |m| m.channel_id());
+    if let Some(mut connection) = ctx.lavalink().get_connection_mut(guild_id) {
+        connection.text_channel_id = text_channel_id;
+    }
+
+    let settings = InactivitySettings::fetch(ctx.db(), guild_id).await?;
+    if empty && !settings.stays_connected() {
         let empty_voice_notice_txt = format!(
             "Joined an empty voice channel. The bot will automatically disconnects if no one else joins in <t:{}:R>.",
-            Utc::now().timestamp() + i64::from(INACTIVITY_TIMEOUT_SECS)
+            settings.disconnect_timestamp()
         );
 
         traced::tokio_spawn(start_inactivity_timeout(
             super::InactivityTimeoutContext::new_via(ctx),
             joined.id,
             text_channel_id,
+            settings.timeout(),
         ));
 
         let empty_voice_notice = note_fol!(empty_voice_notice_txt, ?ctx);
@@ -8,19 +8,22 @@ use twilight_model::{
     id::{marker::ChannelMarker, Id},
 };
 
+use super::channel_status;
 use crate::bot::{
     command::{
         check,
-        macros::{caut, out},
-        model::{BotSlashCommand, Ctx, CtxKind},
+        macros::{caut, out_or_fol},
+        model::{BotSlashCommand, Ctx, RespondViaMessage},
         SlashCtx,
     },
+    core::model::HttpAware,
     error::{
+        command::RespondError,
         component::connection::leave::{self, PreDisconnectCleanupError},
         CommandResult,
     },
     gateway::{ExpectedGuildIdAware, SenderAware},
-    lavalink::{self, LavalinkAware},
+    lavalink::{self, DelegateMethods, FadeSettings, LavalinkAware, PlayerAware, PlayerDataAware},
 };
 
 pub(super) struct LeaveResponse(pub(super) Id<ChannelMarker>);
@@ -41,11 +44,22 @@ pub(super) fn disconnect(
 }
 
 pub(super) async fn pre_disconnect_cleanup(
-    ctx: &(impl ExpectedGuildIdAware + lavalink::LavalinkAware + Sync),
+    ctx: &(impl ExpectedGuildIdAware + lavalink::LavalinkAware + HttpAware + Sync),
 ) -> Result<(), PreDisconnectCleanupError> {
     let guild_id = ctx.guild_id();
     let lavalink = ctx.lavalink();
 
+    if let Some(channel_id) = lavalink.get_connection(guild_id).map(|c| c.channel_id) {
+        if let Err(error) = channel_status::restore(ctx, channel_id).await {
+            tracing::warn!(?error, "restoring channel status failed");
+        }
+    }
+    if let Some(data) = lavalink.get_player_data(guild_id) {
+        let mut data_w = data.write().await;
+        data_w.abort_fade();
+        data_w.abort_queue_end_timeout();
+        data_w.abort_filter_reverts();
+    }
     lavalink.dispatch_queue_clear(guild_id);
     lavalink.drop_connection(guild_id);
     lavalink.delete_player(guild_id).await?;
@@ -53,13 +67,39 @@ pub(super) async fn pre_disconnect_cleanup(
     Ok(())
 }
 
-async fn leave(ctx: &Ctx<impl CtxKind>) -> Result<LeaveResponse, leave::Error> {
+async fn fade_out_before_leaving(
+    ctx: &mut Ctx<impl RespondViaMessage>,
+) -> Result<(), RespondError> {
+    let (Some(player), Some(data)) = (ctx.get_player(), ctx.get_player_data()) else {
+        return Ok(());
+    };
+    let fade_out = match FadeSettings::fetch(ctx.db(), ctx.guild_id()).await {
+        Ok(settings) => settings.fade_out(),
+        Err(error) => {
+            tracing::warn!(?error, "fetching fade settings failed");
+            return Ok(());
+        }
+    };
+    if fade_out.is_zero() {
+        return Ok(());
+    }
+
+    data.write().await.abort_fade();
+    ctx.defer().await?;
+    if let Err(error) = lavalink::fade_out(&player, &data, fade_out).await {
+        tracing::warn!(?error, "fading out before leaving failed");
+    }
+    Ok(())
+}
+
+async fn leave(ctx: &mut Ctx<impl RespondViaMessage>) -> Result<LeaveResponse, leave::Error> {
     let guild_id = ctx.guild_id();
 
     let in_voice = check::in_voice(ctx)?;
     let channel_id = in_voice.channel_id();
     in_voice.with_user()?.only()?;
 
+    fade_out_before_leaving(ctx).await?;
     ctx.lavalink().notify_connection_change(guild_id);
     pre_disconnect_cleanup(ctx).await?;
     disconnect(ctx)?;
@@ -76,9 +116,9 @@ pub struct Leave;
 
 impl BotSlashCommand for Leave {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        match leave(&ctx).await {
+        match leave(&mut ctx).await {
             Ok(LeaveResponse(voice)) => {
-                out!(format!("📎 ~~{}~~", voice.mention()), ctx);
+                out_or_fol!(format!("📎 ~~{}~~", voice.mention()), ctx);
             }
             Err(e) => match e.match_not_in_voice_into() {
                 leave::NotInVoiceMatchedError::NotInVoice(_) => {
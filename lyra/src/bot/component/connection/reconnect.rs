@@ -0,0 +1,375 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use futures::StreamExt;
+use lavalink_rs::{error::LavalinkResult, model::track::TrackData};
+use sqlx::{Pool, Postgres};
+use twilight_gateway::MessageSender;
+use twilight_mention::Mention;
+use twilight_model::{
+    application::interaction::Interaction,
+    channel::{
+        message::{
+            component::{ActionRow, Button, ButtonStyle},
+            Component, ReactionType,
+        },
+        ChannelType,
+    },
+    gateway::payload::outgoing::UpdateVoiceState,
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_util::builder::InteractionResponseDataBuilder;
+
+use crate::bot::{
+    component::{config::self_deafen, tuning},
+    core::{
+        model::{BotStateAware, CacheAware, HttpAware, OwnedBotState, OwnedBotStateAware},
+        r#const::{connection::NETWORK_DROP_CLOSE_CODES, exit_code::NOTICE},
+        traced,
+    },
+    error::component::connection::{ReconnectError, WaitToReconnectError},
+    gateway::{ExpectedGuildIdAware, SenderAware},
+    lavalink::{DelegateMethods, Lavalink, LavalinkAware},
+};
+
+use super::{leave::pre_disconnect_cleanup, InactivityTimeoutContext};
+
+const RECONNECT_BUTTON_ID: &str = "reconnect";
+
+/// How long the queue is kept for after a forced disconnect, if at all
+pub(super) async fn reconnect_grace(
+    db: &Pool<Postgres>,
+    guild_id: Id<GuildMarker>,
+) -> Result<Option<Duration>, sqlx::Error> {
+    let grace_secs = sqlx::query!(
+        r"--sql
+        SELECT reconnect_grace_secs FROM guild_configs WHERE id = $1;",
+        guild_id.get() as i64
+    )
+    .fetch_one(db)
+    .await?
+    .reconnect_grace_secs;
+
+    Ok((grace_secs != 0).then(|| Duration::from_secs(grace_secs as u64)))
+}
+
+/// A queue kept from a destroyed player, to be restored once reconnected
+pub(super) struct SavedQueue {
+    tracks: Vec<TrackData>,
+    requesters: Vec<Id<UserMarker>>,
+    index: usize,
+    position: Duration,
+}
+
+impl SavedQueue {
+    pub(super) const fn new(
+        tracks: Vec<TrackData>,
+        requesters: Vec<Id<UserMarker>>,
+        index: usize,
+    ) -> Self {
+        Self {
+            tracks,
+            requesters,
+            index,
+            position: Duration::ZERO,
+        }
+    }
+
+    /// Takes the queue out of the player, alongside how far into the current track it was
+    pub(super) async fn take(
+        lavalink: &Lavalink,
+        guild_id: Id<GuildMarker>,
+    ) -> LavalinkResult<Option<Self>> {
+        let (Some(player), Some(data)) = (
+            lavalink.get_player_context(guild_id),
+            lavalink.get_player_data(guild_id),
+        ) else {
+            return Ok(None);
+        };
+        let position = Duration::from_millis(player.get_player().await?.state.position);
+
+        let mut data_w = data.write().await;
+        let queue = data_w.queue_mut();
+        let index = queue
+            .current_and_index()
+            .map_or(queue.len(), |(_, index)| index);
+        let (requesters, tracks) = queue
+            .drain_all()
+            .map(|item| (item.requester(), item.into_track()))
+            .unzip();
+
+        Ok(Some(Self {
+            tracks,
+            requesters,
+            index,
+            position,
+        }))
+    }
+
+    const fn is_empty(&self) -> bool {
+        self.tracks.is_empty()
+    }
+}
+
+/// Connects to the voice channel anew and restores the saved queue, returning whether a track was resumed
+pub(super) async fn reconnect(
+    bot: &OwnedBotState,
+    sender: &MessageSender,
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+    text_channel_id: Id<ChannelMarker>,
+    queue: SavedQueue,
+) -> Result<bool, ReconnectError> {
+    let lavalink = bot.lavalink();
+    let channel_type = bot.cache().channel(channel_id).map(|c| c.kind);
+    let self_deaf = self_deafen::enabled(bot.db(), guild_id).await?;
+
+    lavalink.new_connection(guild_id, channel_id, text_channel_id, sender.clone());
+    lavalink.notify_connection_change(guild_id);
+    sender.command(&UpdateVoiceState::new(
+        guild_id, channel_id, self_deaf, false,
+    ))?;
+    if channel_type == Some(ChannelType::GuildStageVoice) {
+        bot.http()
+            .update_current_user_voice_state(guild_id)
+            .channel_id(channel_id)
+            .request_to_speak_timestamp(&Utc::now().to_rfc3339())
+            .await?;
+    }
+    tracing::debug!(
+        "guild {} joined {} without an interaction",
+        guild_id,
+        channel_id
+    );
+
+    let defaults = tuning::player_defaults(bot.db(), guild_id).await?;
+    lavalink.new_player_data(guild_id, defaults).await?;
+
+    let data = lavalink.player_data(guild_id);
+    let mut data_w = data.write().await;
+    let restored = data_w.queue_mut();
+    for (track, requester) in queue.tracks.into_iter().zip(queue.requesters) {
+        restored.enqueue(vec![track], requester);
+    }
+    *restored.index_mut() = queue.index;
+    let current = restored.current().map(|item| item.track().clone());
+    drop(data_w);
+
+    let Some(track) = current else {
+        return Ok(false);
+    };
+    let player = lavalink.player(guild_id);
+    player.play(&track).await?;
+    if !queue.position.is_zero() {
+        player.set_position(queue.position).await?;
+    }
+    Ok(true)
+}
+
+/// Connects to the voice channel outside of an interaction, with an empty queue
+pub async fn connect(
+    bot: &OwnedBotState,
+    sender: &MessageSender,
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+    text_channel_id: Id<ChannelMarker>,
+) -> Result<(), ReconnectError> {
+    let queue = SavedQueue::new(Vec::new(), Vec::new(), 0);
+    reconnect(bot, sender, guild_id, channel_id, text_channel_id, queue).await?;
+    Ok(())
+}
+
+fn reconnect_row() -> [Component; 1] {
+    [Component::ActionRow(ActionRow {
+        components: vec![Component::Button(Button {
+            custom_id: Some(String::from(RECONNECT_BUTTON_ID)),
+            disabled: false,
+            emoji: Some(ReactionType::Unicode {
+                name: String::from("🖇️"),
+            }),
+            label: Some(String::from("Reconnect")),
+            style: ButtonStyle::Primary,
+            url: None,
+        })],
+    })]
+}
+
+fn disconnected_text(channel_id: Id<ChannelMarker>) -> String {
+    format!(
+        "{}📎 ~~{}~~ `(Bot was forcefully disconnected)`",
+        NOTICE,
+        channel_id.mention()
+    )
+}
+
+/// Announces a forced disconnect, offering to reconnect with the kept queue until the grace period ends
+pub(super) async fn offer_reconnect(
+    ctx: &(impl OwnedBotStateAware + SenderAware + ExpectedGuildIdAware),
+    channel_id: Id<ChannelMarker>,
+    text_channel_id: Id<ChannelMarker>,
+    queue: Option<SavedQueue>,
+    grace: Option<Duration>,
+) -> Result<(), WaitToReconnectError> {
+    let disconnected = disconnected_text(channel_id);
+    let (Some(queue), Some(grace)) = (queue.filter(|q| !q.is_empty()), grace) else {
+        ctx.bot()
+            .http()
+            .create_message(text_channel_id)
+            .content(&disconnected)
+            .await?;
+        return Ok(());
+    };
+
+    let content = format!(
+        "{disconnected}\n-# The queue is kept until <t:{}:R>.",
+        Utc::now().timestamp() + grace.as_secs() as i64
+    );
+    let message = ctx
+        .bot()
+        .http()
+        .create_message(text_channel_id)
+        .content(&content)
+        .components(&reconnect_row())
+        .await?
+        .model()
+        .await?;
+
+    traced::tokio_spawn(wait_to_reconnect(
+        WaitToReconnect {
+            bot: ctx.bot_owned(),
+            sender: ctx.sender().clone(),
+            guild_id: ctx.guild_id(),
+            channel_id,
+            text_channel_id,
+            message_id: message.id,
+        },
+        queue,
+        grace,
+    ));
+    Ok(())
+}
+
+struct WaitToReconnect {
+    bot: OwnedBotState,
+    sender: MessageSender,
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+    text_channel_id: Id<ChannelMarker>,
+    message_id: Id<MessageMarker>,
+}
+
+async fn wait_to_reconnect(
+    ctx: WaitToReconnect,
+    queue: SavedQueue,
+    grace: Duration,
+) -> Result<(), WaitToReconnectError> {
+    let bot = &ctx.bot;
+    let mut components = bot
+        .standby()
+        .wait_for_component_stream(ctx.message_id, |i: &Interaction| i.author_id().is_some());
+    let disconnected = disconnected_text(ctx.channel_id);
+    let deadline = tokio::time::sleep(grace);
+    tokio::pin!(deadline);
+
+    loop {
+        tokio::select! {
+            Some(interaction) = components.next() => {
+                let i = bot.interaction().await?.interfaces(&interaction);
+                let author_in_channel = interaction.author_id().is_some_and(|author_id| {
+                    bot.cache()
+                        .voice_state(author_id, ctx.guild_id)
+                        .is_some_and(|v| v.channel_id() == ctx.channel_id)
+                });
+                if !author_in_channel {
+                    i.ephem(format!("Join {} first to reconnect.", ctx.channel_id.mention()))
+                        .await?;
+                    continue;
+                }
+
+                let connected_again = bot.lavalink().get_connection(ctx.guild_id).is_some();
+                let reconnected_text = if connected_again {
+                    "-# The kept queue was discarded, as the bot has already been connected again."
+                } else {
+                    "-# 🖇️ Reconnected with the kept queue."
+                };
+                let data = InteractionResponseDataBuilder::new()
+                    .content(format!("{disconnected}\n{reconnected_text}"))
+                    .components([])
+                    .build();
+                i.update_message_with(Some(data)).await?;
+
+                if !connected_again {
+                    reconnect(
+                        bot,
+                        &ctx.sender,
+                        ctx.guild_id,
+                        ctx.channel_id,
+                        ctx.text_channel_id,
+                        queue,
+                    )
+                    .await?;
+                }
+                return Ok(());
+            }
+            () = &mut deadline => break,
+        }
+    }
+
+    bot.http()
+        .update_message(ctx.text_channel_id, ctx.message_id)
+        .content(Some(&format!(
+            "{disconnected}\n-# The kept queue was discarded."
+        )))
+        .components(Some(&[]))
+        .await?;
+    Ok(())
+}
+
+/// Reconnects when the voice connection was dropped by the network rather than by a moderator, keeping the queue
+pub async fn rejoin_after_network_drop(
+    bot: OwnedBotState,
+    guild_id: Id<GuildMarker>,
+    close_code: u16,
+) -> Result<(), ReconnectError> {
+    if !NETWORK_DROP_CLOSE_CODES.contains(&close_code) {
+        return Ok(());
+    }
+    if reconnect_grace(bot.db(), guild_id).await?.is_none() {
+        return Ok(());
+    }
+    let Some((channel_id, text_channel_id, sender)) = bot
+        .lavalink()
+        .get_connection(guild_id)
+        .map(|c| (c.channel_id, c.text_channel_id, c.sender().clone()))
+    else {
+        return Ok(());
+    };
+
+    tracing::warn!(
+        "guild {} voice connection dropped with code {}",
+        guild_id,
+        close_code
+    );
+    let ctx = InactivityTimeoutContext::new(bot.clone(), sender.clone(), guild_id);
+    let queue = SavedQueue::take(bot.lavalink(), guild_id).await?;
+    bot.lavalink().notify_connection_change(guild_id);
+    pre_disconnect_cleanup(&ctx).await?;
+
+    if let Some(queue) = queue {
+        reconnect(&bot, &sender, guild_id, channel_id, text_channel_id, queue).await?;
+    } else {
+        connect(&bot, &sender, guild_id, channel_id, text_channel_id).await?;
+    }
+    bot.http()
+        .create_message(text_channel_id)
+        .content(&format!(
+            "{}🖇️ {} `(Reconnected after the voice connection dropped)`",
+            NOTICE,
+            channel_id.mention()
+        ))
+        .await?;
+    Ok(())
+}
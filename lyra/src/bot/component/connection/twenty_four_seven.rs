@@ -0,0 +1,141 @@
+use sqlx::{Pool, Postgres};
+use twilight_gateway::{Event, MessageSender};
+use twilight_mention::Mention;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::bot::{
+    core::{
+        model::{CacheAware, HttpAware, OwnedBotState},
+        r#const::connection::WAIT_FOR_GUILD_CREATE_TIMEOUT,
+    },
+    error::component::connection::RejoinTwentyFourSevenError,
+    lavalink::{LavalinkAware, PlayerDataRwLockArc},
+};
+
+use super::reconnect::{reconnect, SavedQueue};
+
+/// Saves the queue of a guild with 24/7 mode on, so that it can be resumed when rejoining after a restart
+pub async fn save_queue(
+    db: &Pool<Postgres>,
+    guild_id: Id<GuildMarker>,
+    data: &PlayerDataRwLockArc,
+) -> Result<(), sqlx::Error> {
+    let data_r = data.read().await;
+    let queue = data_r.queue();
+    let (tracks, requesters): (Vec<_>, Vec<_>) = queue
+        .iter()
+        .map(|item| (item.track().encoded.clone(), item.requester().get() as i64))
+        .unzip();
+    let index = queue
+        .current_and_index()
+        .map_or(queue.len(), |(_, index)| index);
+    drop(data_r);
+
+    sqlx::query!(
+        r"--sql
+        UPDATE guild_configs
+        SET
+            twenty_four_seven_queue = $2,
+            twenty_four_seven_requesters = $3,
+            twenty_four_seven_queue_index = $4
+        WHERE id = $1 AND twenty_four_seven;
+        ",
+        guild_id.get() as i64,
+        &tracks,
+        &requesters,
+        index as i32,
+    )
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+/// Rejoins the recorded voice channel of a guild with 24/7 mode on, resuming its saved queue
+pub async fn rejoin(
+    bot: OwnedBotState,
+    sender: MessageSender,
+    guild_id: Id<GuildMarker>,
+) -> Result<(), RejoinTwentyFourSevenError> {
+    let guild_create = bot
+        .standby()
+        .wait_for(guild_id, |e: &Event| matches!(e, Event::GuildCreate(_)));
+    if bot.cache().guild(guild_id).is_none() {
+        let _ = tokio::time::timeout(*WAIT_FOR_GUILD_CREATE_TIMEOUT, guild_create).await;
+    }
+
+    let lavalink = bot.lavalink();
+    if lavalink.get_connection(guild_id).is_some() {
+        return Ok(());
+    }
+
+    let config = sqlx::query!(
+        r"--sql
+        SELECT
+            twenty_four_seven_channel,
+            COALESCE(music_channel, twenty_four_seven_text_channel) AS twenty_four_seven_text_channel,
+            twenty_four_seven_queue,
+            twenty_four_seven_requesters,
+            twenty_four_seven_queue_index
+        FROM guild_configs WHERE id = $1 AND twenty_four_seven;
+        ",
+        guild_id.get() as i64
+    )
+    .fetch_optional(bot.db())
+    .await?;
+    let Some(config) = config else {
+        return Ok(());
+    };
+    let (Some(channel_id), Some(text_channel_id)) = (
+        config.twenty_four_seven_channel,
+        config.twenty_four_seven_text_channel,
+    ) else {
+        return Ok(());
+    };
+    let channel_id = Id::<ChannelMarker>::new(channel_id as u64);
+    let text_channel_id = Id::<ChannelMarker>::new(text_channel_id as u64);
+
+    if bot.cache().channel(channel_id).is_none() {
+        tracing::warn!(
+            "guild {} 24/7 voice {} no longer exists",
+            guild_id,
+            channel_id
+        );
+        return Ok(());
+    }
+
+    let tracks = lavalink
+        .decode_tracks(guild_id, &config.twenty_four_seven_queue)
+        .await?;
+    let requesters = config
+        .twenty_four_seven_requesters
+        .into_iter()
+        .map(|r| Id::<UserMarker>::new(r as u64))
+        .collect();
+    let queue = SavedQueue::new(
+        tracks,
+        requesters,
+        config.twenty_four_seven_queue_index as usize,
+    );
+    let resumed = if reconnect(&bot, &sender, guild_id, channel_id, text_channel_id, queue).await? {
+        " and resumed the queue"
+    } else {
+        ""
+    };
+    tracing::debug!(
+        "guild {} rejoined {} as 24/7 mode is on",
+        guild_id,
+        channel_id
+    );
+    bot.http()
+        .create_message(text_channel_id)
+        .content(&format!(
+            "🔁🖇️ {} `(Rejoined{} as 24/7 mode is on)`",
+            channel_id.mention(),
+            resumed
+        ))
+        .await?;
+    Ok(())
+}
@@ -5,16 +5,24 @@ mod play;
 mod remove;
 mod remove_range;
 mod repeat;
+mod search;
 mod shuffle;
+mod song_request;
 
 pub use clear::Clear;
 pub use fair_queue::FairQueue;
-pub use play::{AddToQueue, Autocomplete as PlayAutocomplete, File as PlayFile, Play};
+pub use play::{AddToQueue, Autocomplete as PlayAutocomplete, File as PlayFile, Play, PlaySource};
 pub use r#move::{Autocomplete as MoveAutocomplete, Move};
 pub use remove::{Autocomplete as RemoveAutocomplete, Remove};
 pub use remove_range::{Autocomplete as RemoveRangeAutocomplete, RemoveRange};
 pub use repeat::Repeat;
+pub use search::Search;
 pub use shuffle::Shuffle;
+pub use song_request::{
+    handle_message_create as handle_song_request,
+    live_message_content as song_request_message_content,
+    update_live_message as update_song_request_message, SongRequestChannel,
+};
 
 use std::{collections::HashSet, num::NonZeroUsize};
 
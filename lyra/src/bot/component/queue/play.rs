@@ -1,5 +1,5 @@
 use chrono::Duration;
-use futures::future;
+use futures::{future, StreamExt};
 use itertools::{Either, Itertools};
 use lavalink_rs::{
     client::LavalinkClient,
@@ -9,23 +9,48 @@ use lavalink_rs::{
     },
 };
 use linkify::{LinkFinder, LinkKind};
+use rand::seq::SliceRandom;
+use sqlx::{Pool, Postgres};
 use twilight_interactions::command::{
     AutocompleteValue, CommandModel, CommandOption, CreateCommand, CreateOption,
 };
 use twilight_model::{
-    application::command::{Command, CommandOptionChoice, CommandOptionChoiceValue, CommandType},
-    channel::{Attachment, Message},
+    application::{
+        command::{Command, CommandOptionChoice, CommandOptionChoiceValue, CommandType},
+        interaction::Interaction,
+    },
+    channel::{
+        message::{
+            component::{ActionRow, Button, ButtonStyle},
+            Component, ReactionType,
+        },
+        Attachment, Message,
+    },
+    gateway::payload::incoming::InteractionCreate,
     id::{marker::GuildMarker, Id},
 };
 use twilight_util::builder::command::CommandBuilder;
 
 use crate::bot::{
     command::{
-        macros::{bad, crit, out_or_fol, what},
-        model::{BotAutocomplete, BotMessageCommand, BotSlashCommand, Ctx, RespondViaMessage},
+        macros::{bad, bad_or_fol, caut_fol, crit_or_fol, out_or_fol, what_or_fol},
+        model::{
+            BotAutocomplete, BotMessageCommand, BotSlashCommand, Ctx, CtxKind, RespondViaMessage,
+        },
         util, AutocompleteCtx, MessageCtx, SlashCtx,
     },
-    core::r#const::{discord::COMMAND_CHOICES_LIMIT, misc::ADD_TRACKS_WRAP_LIMIT, regex},
+    core::{
+        model::BotStateAware,
+        r#const::{
+            discord::COMMAND_CHOICES_LIMIT,
+            misc::{
+                ADD_TRACKS_BACKGROUND_CHUNK_INTERVAL, ADD_TRACKS_BACKGROUND_CHUNK_SIZE,
+                ADD_TRACKS_BACKGROUND_THRESHOLD, ADD_TRACKS_WRAP_LIMIT, DEFER_LOADING_TRACKS_AFTER,
+            },
+            regex,
+            text::UNKNOWN_ARTIST,
+        },
+    },
     error::{
         command::AutocompleteResult,
         component::queue::play::{self, LoadTrackProcessManyError, QueryError},
@@ -33,20 +58,80 @@ use crate::bot::{
     },
     ext::util::{PrettifiedTimestamp, PrettyJoiner, PrettyTruncator, ViaGrapheme},
     gateway::ExpectedGuildIdAware,
-    lavalink::{CorrectPlaylistInfo, CorrectTrackInfo, DelegateMethods, LavalinkAware},
+    lavalink::{
+        CorrectPlaylistInfo, CorrectTrackInfo, DelegateMethods, LavalinkAware, PluginInfo,
+        PluginPlaylistInfo, PluginTrackInfo, PreviewAware,
+    },
 };
 
-struct LoadTrackContext {
+pub(super) struct LoadTrackContext {
     guild_id: Id<GuildMarker>,
     lavalink: LavalinkClient,
+    search_prefixes: Box<[Box<str>]>,
+    playlist_options: PlaylistOptions,
+}
+
+/// Which tracks of a loaded playlist to enqueue, and in what order
+#[derive(Default, Clone, Copy)]
+struct PlaylistOptions {
+    /// 1-indexed, inclusive
+    from: Option<usize>,
+    /// 1-indexed, inclusive
+    to: Option<usize>,
+    limit: Option<usize>,
+    shuffle: bool,
+    from_selected: bool,
+}
+
+impl PlaylistOptions {
+    fn select(self, mut tracks: Vec<TrackData>, selected_track: Option<usize>) -> Vec<TrackData> {
+        let len = tracks.len();
+        let start = self
+            .from
+            .map(|f| f - 1)
+            .or_else(|| selected_track.filter(|_| self.from_selected))
+            .unwrap_or(0)
+            .min(len);
+        let end = self.to.unwrap_or(len).clamp(start, len);
+        let end = self.limit.map_or(end, |l| end.min(start + l));
+
+        tracks.truncate(end);
+        tracks.drain(..start);
+        if self.shuffle {
+            tracks.shuffle(&mut rand::thread_rng());
+        }
+        tracks
+    }
 }
 
 impl LoadTrackContext {
-    fn new_via(ctx: &(impl ExpectedGuildIdAware + LavalinkAware)) -> Self {
+    pub(super) fn new_via(ctx: &(impl ExpectedGuildIdAware + LavalinkAware)) -> Self {
         Self {
             guild_id: ctx.guild_id(),
             lavalink: ctx.lavalink().clone_inner(),
+            search_prefixes: Box::default(),
+            playlist_options: PlaylistOptions::default(),
+        }
+    }
+
+    const fn with_playlist_options(mut self, playlist_options: PlaylistOptions) -> Self {
+        self.playlist_options = playlist_options;
+        self
+    }
+
+    pub(super) fn with_search_prefixes(mut self, search_prefixes: Box<[Box<str>]>) -> Self {
+        self.search_prefixes = search_prefixes;
+        self
+    }
+}
+
+const fn has_results(loaded: &LoadedTracks) -> bool {
+    match loaded.load_type {
+        TrackLoadType::Track | TrackLoadType::Playlist => true,
+        TrackLoadType::Search => {
+            matches!(loaded.data, Some(TrackLoadData::Search(ref tracks)) if !tracks.is_empty())
         }
+        TrackLoadType::Empty | TrackLoadType::Error => false,
     }
 }
 
@@ -55,12 +140,31 @@ impl LoadTrackContext {
         self.lavalink.load_tracks(self.guild_id, query).await
     }
 
-    async fn process_many(
+    /// Loads the query as-is if it is a URL, otherwise searches it with each of the search prefixes in turn until one of them returns results
+    async fn process_with_fallback(&self, query: &str) -> LavalinkResult<LoadedTracks> {
+        let Some((last_prefix, prefixes)) = self
+            .search_prefixes
+            .split_last()
+            .filter(|_| !regex::URL.is_match(query))
+        else {
+            return self.process(query).await;
+        };
+
+        for prefix in prefixes {
+            let loaded = self.process(&format!("{prefix}{query}")).await?;
+            if has_results(&loaded) {
+                return Ok(loaded);
+            }
+        }
+        self.process(&format!("{last_prefix}{query}")).await
+    }
+
+    pub(super) async fn process_many(
         &self,
         queries: impl IntoIterator<Item = Box<str>> + Send,
     ) -> Result<LoadTrackResults, LoadTrackProcessManyError> {
         let queries = queries.into_iter().map(|query| async move {
-            let loaded = self.process(&query).await?;
+            let loaded = self.process_with_fallback(&query).await?;
             match loaded.load_type {
                 TrackLoadType::Track => {
                     let Some(TrackLoadData::Track(t)) = loaded.data else {
@@ -69,11 +173,30 @@ impl LoadTrackContext {
                     Ok(LoadTrackResult::Track(t))
                 }
                 TrackLoadType::Playlist => {
-                    Ok(LoadTrackResult::Playlist(Playlist::new(loaded, query)))
+                    let playlist = Playlist::new(loaded, query, self.playlist_options);
+                    if playlist.tracks.is_empty() {
+                        return Err(LoadTrackProcessManyError::Query(QueryError::NoMatches(
+                            playlist.uri,
+                        )));
+                    }
+                    Ok(LoadTrackResult::Playlist(playlist))
+                }
+                TrackLoadType::Search if self.search_prefixes.is_empty() => Err(
+                    LoadTrackProcessManyError::Query(QueryError::SearchResult(query)),
+                ),
+                TrackLoadType::Search => {
+                    let Some(TrackLoadData::Search(tracks)) = loaded.data else {
+                        unreachable!()
+                    };
+                    tracks.into_iter().next().map_or_else(
+                        || {
+                            Err(LoadTrackProcessManyError::Query(QueryError::NoMatches(
+                                query,
+                            )))
+                        },
+                        |t| Ok(LoadTrackResult::Track(t)),
+                    )
                 }
-                TrackLoadType::Search => Err(LoadTrackProcessManyError::Query(
-                    QueryError::SearchResult(query),
-                )),
                 TrackLoadType::Empty => Err(LoadTrackProcessManyError::Query(
                     QueryError::NoMatches(query),
                 )),
@@ -91,21 +214,28 @@ impl LoadTrackContext {
 struct Playlist {
     uri: Box<str>,
     info: PlaylistInfo,
+    plugin_info: Option<PluginPlaylistInfo>,
     tracks: Box<[TrackData]>,
 }
 
 impl Playlist {
-    fn new(loaded: LoadedTracks, uri: Box<str>) -> Self {
+    fn new(loaded: LoadedTracks, uri: Box<str>, options: PlaylistOptions) -> Self {
         match loaded.load_type {
             TrackLoadType::Playlist => {
                 let Some(TrackLoadData::Playlist(data)) = loaded.data else {
                     unreachable!()
                 };
+                let selected_track = data
+                    .info
+                    .selected_track
+                    .and_then(|i| usize::try_from(i).ok())
+                    .filter(|&i| i < data.tracks.len());
 
                 Self {
                     uri,
+                    plugin_info: data.parse_plugin_info(),
                     info: data.info,
-                    tracks: data.tracks.into(),
+                    tracks: options.select(data.tracks, selected_track).into(),
                 }
             }
             _ => panic!("`loaded.load_type` not `LoadType::PlaylistLoaded`"),
@@ -120,7 +250,7 @@ enum LoadTrackResult {
 }
 
 #[must_use]
-struct LoadTrackResults(Box<[LoadTrackResult]>);
+pub(super) struct LoadTrackResults(Box<[LoadTrackResult]>);
 
 impl LoadTrackResults {
     fn split(&self) -> (Vec<&TrackData>, Vec<&Playlist>) {
@@ -165,19 +295,39 @@ trait AutocompleteResultPrettify {
 
 impl AutocompleteResultPrettify for TrackData {
     fn prettify(&mut self) -> String {
+        let plugin_info = self.parse_plugin_info();
         let track_info = &mut self.info;
 
         let track_length =
             PrettifiedTimestamp::from(Duration::milliseconds(track_info.length as i64));
         let title = track_info.take_and_correct_title();
         let author = track_info.take_and_correct_author();
+        let preview = if plugin_info
+            .as_ref()
+            .is_some_and(PluginTrackInfo::is_preview)
+        {
+            "⚠️Preview "
+        } else {
+            ""
+        };
 
-        format!(
-            "⌛{} 👤{} 🎵{}",
-            track_length,
-            author.pretty_truncate(15),
-            title.pretty_truncate(55)
-        )
+        match plugin_info.as_ref().and_then(PluginTrackInfo::album_name) {
+            Some(album_name) => format!(
+                "{}⌛{} 👤{} 💿{} 🎵{}",
+                preview,
+                track_length,
+                author.pretty_truncate(15),
+                album_name.pretty_truncate(15),
+                title.pretty_truncate(40)
+            ),
+            None => format!(
+                "{}⌛{} 👤{} 🎵{}",
+                preview,
+                track_length,
+                author.pretty_truncate(15),
+                title.pretty_truncate(55)
+            ),
+        }
     }
 }
 
@@ -187,18 +337,32 @@ impl AutocompleteResultPrettify for LoadedTracks {
             unreachable!()
         };
 
+        let plugin_info = data.parse_plugin_info();
         let name = data.info.take_and_correct_name();
         let track_length = PrettifiedTimestamp::from(Duration::milliseconds(
             data.tracks.iter().map(|t| t.info.length as i64).sum(),
         ));
         let track_count = data.tracks.len();
 
-        format!(
-            "📚{} tracks ⌛{} 🎵{}",
-            track_count,
-            track_length,
-            name.pretty_truncate(80)
-        )
+        match plugin_info {
+            Some(plugin_info) => format!(
+                "📚{} tracks ⌛{} 🏷️{} 👤{} 🎵{}",
+                plugin_info.total_tracks().unwrap_or(track_count),
+                track_length,
+                plugin_info.kind(),
+                plugin_info
+                    .author()
+                    .unwrap_or(UNKNOWN_ARTIST)
+                    .pretty_truncate(15),
+                name.pretty_truncate(50)
+            ),
+            None => format!(
+                "📚{} tracks ⌛{} 🎵{}",
+                track_count,
+                track_length,
+                name.pretty_truncate(80)
+            ),
+        }
     }
 }
 
@@ -216,21 +380,13 @@ impl BotAutocomplete for Autocomplete {
             AutocompleteValue::Focused(q) => Some(q),
             _ => None,
         })
-        .map(|q| {
-            let source = self.source.unwrap_or_default();
-            (!regex::URL.is_match(&q))
-                .then(|| format!("{}{}", source.value(), q).into_boxed_str())
-                .unwrap_or_else(|| q.into_boxed_str())
-        })
+        .map(String::into_boxed_str)
         .expect("exactly one option is focused");
 
-        let guild_id = ctx.guild_id();
-        let load_ctx = LoadTrackContext {
-            guild_id,
-            lavalink: ctx.lavalink().clone_inner(),
-        };
+        let search_prefixes = search_prefixes(&ctx, self.source).await?;
+        let load_ctx = LoadTrackContext::new_via(&ctx).with_search_prefixes(search_prefixes);
 
-        let mut loaded = load_ctx.process(&query).await?;
+        let mut loaded = load_ctx.process_with_fallback(&query).await?;
         let choices = match loaded.load_type {
             TrackLoadType::Search => {
                 let Some(TrackLoadData::Search(tracks)) = loaded.data else {
@@ -301,14 +457,66 @@ impl BotAutocomplete for Autocomplete {
     }
 }
 
-async fn play(
+/// Gets the search prefixes to try in order, which is only the given source's if there is one, or the guild's configured search sources otherwise
+async fn search_prefixes(
+    ctx: &Ctx<impl CtxKind>,
+    source: Option<PlaySource>,
+) -> Result<Box<[Box<str>]>, sqlx::Error> {
+    if let Some(source) = source {
+        return Ok(Box::from([Box::from(source.value())]));
+    }
+
+    guild_search_prefixes(ctx.db(), ctx.guild_id()).await
+}
+
+/// Gets the guild's configured search sources as search prefixes to try in order
+pub(super) async fn guild_search_prefixes(
+    db: &Pool<Postgres>,
+    guild_id: Id<GuildMarker>,
+) -> Result<Box<[Box<str>]>, sqlx::Error> {
+    let search_sources = sqlx::query!(
+        r"--sql
+        SELECT search_sources FROM guild_configs WHERE id = $1;",
+        guild_id.get() as i64
+    )
+    .fetch_one(db)
+    .await?
+    .search_sources;
+    Ok(search_sources
+        .into_iter()
+        .map(String::into_boxed_str)
+        .collect())
+}
+
+pub(super) async fn play(
     ctx: &mut Ctx<impl RespondViaMessage>,
     queries: impl IntoIterator<Item = Box<str>> + Send,
+) -> Result<(), play::Error> {
+    play_with_options(ctx, queries, Box::default(), PlaylistOptions::default()).await
+}
+
+async fn play_with_options(
+    ctx: &mut Ctx<impl RespondViaMessage>,
+    queries: impl IntoIterator<Item = Box<str>> + Send,
+    search_prefixes: Box<[Box<str>]>,
+    playlist_options: PlaylistOptions,
 ) -> Result<(), play::Error> {
     let guild_id = ctx.guild_id();
 
-    let load_ctx = LoadTrackContext::new_via(ctx);
-    match load_ctx.process_many(queries).await {
+    let load_ctx = LoadTrackContext::new_via(ctx)
+        .with_search_prefixes(search_prefixes)
+        .with_playlist_options(playlist_options);
+    let process_many = load_ctx.process_many(queries);
+    tokio::pin!(process_many);
+
+    let loaded = tokio::select! {
+        loaded = &mut process_many => loaded,
+        () = tokio::time::sleep(*DEFER_LOADING_TRACKS_AFTER) => {
+            ctx.defer().await?;
+            process_many.await
+        }
+    };
+    match loaded {
         Ok(results) => {
             let (tracks, playlists) = results.split();
 
@@ -318,11 +526,24 @@ async fn play(
                 1..=ADD_TRACKS_WRAP_LIMIT => tracks
                     .iter()
                     .map(|t| {
-                        format!(
+                        let track_text = format!(
                             "[`{}`](<{}>)",
                             t.info.corrected_title(),
                             t.info.uri.as_ref().expect("track is nonlocal")
-                        )
+                        );
+                        let Some(plugin_info) = t.parse_plugin_info() else {
+                            return track_text;
+                        };
+
+                        let album_text = plugin_info
+                            .album_name()
+                            .map(|a| format!(" from album `{a}`"))
+                            .unwrap_or_default();
+                        let artist_text = plugin_info.artist_url().map_or_else(
+                            || format!(" by `{}`", t.info.corrected_author()),
+                            |u| format!(" by [`{}`](<{}>)", t.info.corrected_author(), u),
+                        );
+                        format!("{track_text}{artist_text}{album_text}")
                     })
                     .collect::<Vec<_>>()
                     .pretty_join_with_and(),
@@ -335,11 +556,32 @@ async fn play(
                 1..=ADD_TRACKS_WRAP_LIMIT => playlists
                     .iter()
                     .map(|p| {
+                        let Some(ref plugin_info) = p.plugin_info else {
+                            return format!(
+                                "`{} tracks` from playlist [`{}`](<{}>)",
+                                p.tracks.len(),
+                                p.info.corrected_name(),
+                                p.uri
+                            );
+                        };
+
+                        let tracks_text = match plugin_info.total_tracks() {
+                            Some(total) if total != p.tracks.len() => {
+                                format!("`{} tracks` (out of `{}`)", p.tracks.len(), total)
+                            }
+                            _ => format!("`{} tracks`", p.tracks.len()),
+                        };
+                        let author_text = plugin_info
+                            .author()
+                            .map(|a| format!(" by `{a}`"))
+                            .unwrap_or_default();
                         format!(
-                            "`{} tracks` from playlist [`{}`](<{}>)",
-                            p.tracks.len(),
+                            "{} from {} [`{}`](<{}>){}",
+                            tracks_text,
+                            plugin_info.kind(),
                             p.info.corrected_name(),
-                            p.uri
+                            plugin_info.url().unwrap_or(&p.uri),
+                            author_text
                         )
                     })
                     .collect::<Vec<_>>()
@@ -369,27 +611,48 @@ async fn play(
 
             util::auto_new_player_data(ctx).await?;
 
-            ctx.lavalink()
-                .player_data(guild_id)
-                .write()
-                .await
-                .queue_mut()
-                .enqueue(total_tracks, ctx.author_id());
+            let previews = total_tracks.iter().filter(|t| t.is_preview()).count();
+            let added_text = format!("{plus} Added {enqueued_text}");
+
+            if total_tracks.len() > ADD_TRACKS_BACKGROUND_THRESHOLD {
+                enqueue_in_background(ctx, total_tracks, &added_text).await?;
+            } else {
+                ctx.lavalink()
+                    .player_data(guild_id)
+                    .write()
+                    .await
+                    .queue_mut()
+                    .enqueue(total_tracks, ctx.author_id());
 
-            ctx.lavalink().player(guild_id).play(&first_track).await?;
+                ctx.lavalink().player(guild_id).play(&first_track).await?;
 
-            out_or_fol!(format!("{} Added {}", plus, enqueued_text), ctx);
+                out_or_fol!(added_text, ?ctx);
+            }
+            if previews != 0 {
+                let previews_text = if previews == 1 {
+                    String::from("`1` of the added tracks is")
+                } else {
+                    format!("`{previews}` of the added tracks are")
+                };
+                caut_fol!(
+                    format!(
+                        "{previews_text} only a **30-second preview**, as the full track is not available from the source."
+                    ),
+                    ctx
+                );
+            }
+            Ok(())
         }
         Err(e) => match e {
             LoadTrackProcessManyError::Query(query) => match query {
                 QueryError::LoadFailed(LoadFailedError(query)) => {
-                    crit!(format!("Failed to load tracks for query: `{}`", query), ctx);
+                    crit_or_fol!(format!("Failed to load tracks for query: `{}`", query), ctx);
                 }
                 QueryError::NoMatches(query) => {
-                    what!(format!("No matches found for query: `{}`", query), ctx);
+                    what_or_fol!(format!("No matches found for query: `{}`", query), ctx);
                 }
                 QueryError::SearchResult(query) => {
-                    bad!(
+                    bad_or_fol!(
                         format!(
                             "Given query is not a URL: `{}`. Try using the command's autocomplete to search for tracks.",
                             query
@@ -403,8 +666,105 @@ async fn play(
     }
 }
 
-#[derive(CommandOption, CreateOption, Default)]
-enum PlaySource {
+const CANCEL_ADDING_BUTTON_ID: &str = "cancel-adding";
+
+fn adding_progress_text(added_text: &str, added: usize, total: usize) -> String {
+    format!("{added_text}\n-# ⏳ Adding tracks to the queue... `{added}`/`{total}`")
+}
+
+/// Enqueues the first chunk of the tracks and starts playing immediately, then enqueues the rest in chunks over time, updating the response with the progress until done or cancelled
+async fn enqueue_in_background(
+    ctx: &mut Ctx<impl RespondViaMessage>,
+    mut tracks: Vec<TrackData>,
+    added_text: &str,
+) -> Result<(), play::Error> {
+    let guild_id = ctx.guild_id();
+    let author_id = ctx.author_id();
+    let total = tracks.len();
+
+    let mut rest = tracks.split_off(ADD_TRACKS_BACKGROUND_CHUNK_SIZE.min(total));
+    let first_track = tracks.first().expect("tracks is non-empty").clone();
+    let mut added = tracks.len();
+    ctx.lavalink()
+        .player_data(guild_id)
+        .write()
+        .await
+        .queue_mut()
+        .enqueue(tracks, author_id);
+    ctx.lavalink().player(guild_id).play(&first_track).await?;
+
+    let cancel_button = Component::Button(Button {
+        custom_id: Some(String::from(CANCEL_ADDING_BUTTON_ID)),
+        disabled: false,
+        emoji: Some(ReactionType::Unicode {
+            name: String::from("✖️"),
+        }),
+        label: Some(String::from("Cancel")),
+        style: ButtonStyle::Danger,
+        url: None,
+    });
+    let row = [Component::ActionRow(ActionRow {
+        components: vec![cancel_button],
+    })];
+
+    let progress_text = adding_progress_text(added_text, added, total);
+    let message = if ctx.acknowledged() {
+        ctx.update_content_and_components(&progress_text, &row)
+            .await?
+            .model()
+            .await?
+    } else {
+        ctx.respond_with_components(progress_text, row.clone())
+            .await?
+            .model()
+            .await?
+    };
+
+    let mut components = ctx
+        .bot()
+        .standby()
+        .wait_for_component_stream(message.id, move |i: &Interaction| {
+            i.author_id() == Some(author_id)
+        });
+
+    let mut cancelled = false;
+    while !rest.is_empty() {
+        tokio::select! {
+            Some(interaction) = components.next() => {
+                ctx.to_component_interaction(Box::new(InteractionCreate(interaction)))
+                    .update_message_embeds_and_components([], [])
+                    .await?;
+                cancelled = true;
+                break;
+            }
+            () = tokio::time::sleep(*ADD_TRACKS_BACKGROUND_CHUNK_INTERVAL) => {}
+        }
+
+        let Some(data) = ctx.lavalink().get_player_data(guild_id) else {
+            cancelled = true;
+            break;
+        };
+        let chunk = rest
+            .drain(..ADD_TRACKS_BACKGROUND_CHUNK_SIZE.min(rest.len()))
+            .collect::<Vec<_>>();
+        added += chunk.len();
+        data.write().await.queue_mut().enqueue(chunk, author_id);
+
+        ctx.update_content_and_components(&adding_progress_text(added_text, added, total), &row)
+            .await?;
+    }
+
+    let done_text = if cancelled {
+        format!("{added_text}\n-# ✖️ Stopped adding tracks. Only `{added}` out of `{total}` tracks were added.")
+    } else {
+        added_text.to_owned()
+    };
+    ctx.update_content_and_components(&done_text, &[]).await?;
+    Ok(())
+}
+
+#[derive(CommandOption, CreateOption, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlaySource {
     #[default]
     #[option(name = "Youtube", value = "ytsearch:")]
     Youtube,
@@ -420,6 +780,19 @@ enum PlaySource {
     Spotify,
 }
 
+impl PlaySource {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Youtube => "Youtube",
+            Self::YoutubeMusic => "Youtube Music",
+            Self::SoundCloud => "SoundCloud",
+            Self::DeezerQuery => "Deezer (Search Query)",
+            Self::DeezerIsrc => "Deezer (ISRC)",
+            Self::Spotify => "Spotify",
+        }
+    }
+}
+
 /// Adds track(s) to the queue
 #[derive(CreateCommand, CommandModel)]
 #[command(name = "play", dm_permission = false)]
@@ -439,9 +812,21 @@ pub struct Play {
     /// What song? [search query / direct link] (5)
     #[command(autocomplete = true)]
     query_5: Option<String>,
-    /// Search from where? (if not given, Youtube)
-    #[command(rename = "source")]
-    _source: Option<PlaySource>,
+    /// Search from where? (if not given, the server's search sources)
+    source: Option<PlaySource>,
+    /// For playlists, start from which track? (if not given, the first track)
+    #[command(min_value = 1)]
+    playlist_from: Option<i64>,
+    /// For playlists, end at which track? (if not given, the last track)
+    #[command(min_value = 1)]
+    playlist_to: Option<i64>,
+    /// For playlists, add at most how many tracks?
+    #[command(min_value = 1)]
+    playlist_limit: Option<i64>,
+    /// For playlists, shuffle the tracks before adding? (if not given, no)
+    shuffle_playlist: Option<bool>,
+    /// For playlists, start from the track the link points to? (if not given, no)
+    from_selected_track: Option<bool>,
 }
 
 impl BotSlashCommand for Play {
@@ -457,7 +842,24 @@ impl BotSlashCommand for Play {
         .flatten()
         .map(String::into_boxed_str);
 
-        Ok(play(&mut ctx, queries).await?)
+        if let (Some(from), Some(to)) = (self.playlist_from, self.playlist_to) {
+            if from > to {
+                bad!(
+                    format!("Invalid playlist range: `{}` to `{}`.", from, to),
+                    ctx
+                );
+            }
+        }
+        let playlist_options = PlaylistOptions {
+            from: self.playlist_from.map(|f| f as usize),
+            to: self.playlist_to.map(|t| t as usize),
+            limit: self.playlist_limit.map(|l| l as usize),
+            shuffle: self.shuffle_playlist.unwrap_or_default(),
+            from_selected: self.from_selected_track.unwrap_or_default(),
+        };
+
+        let search_prefixes = search_prefixes(&ctx, self.source).await?;
+        Ok(play_with_options(&mut ctx, queries, search_prefixes, playlist_options).await?)
     }
 }
 
@@ -503,7 +905,7 @@ impl BotSlashCommand for File {
     }
 }
 
-fn extract_queries(message: &Message) -> Vec<Box<str>> {
+pub(super) fn extract_queries(message: &Message) -> Vec<Box<str>> {
     let mut link_finder = LinkFinder::new();
     link_finder.kinds(&[LinkKind::Url]);
 
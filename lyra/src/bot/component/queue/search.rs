@@ -0,0 +1,375 @@
+use chrono::Duration;
+use futures::StreamExt;
+use lavalink_rs::{
+    error::LavalinkResult,
+    model::track::{PlaylistData, TrackData},
+};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::{
+    application::interaction::{Interaction, InteractionData},
+    channel::message::{
+        component::{ActionRow, SelectMenu, SelectMenuOption, SelectMenuType},
+        Component, Embed,
+    },
+    gateway::payload::incoming::InteractionCreate,
+};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFooterBuilder};
+use twilight_validate::embed::EmbedValidationError;
+
+use crate::bot::{
+    command::{
+        macros::{bad, crit_or_fol, what, what_or_fol},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    core::{
+        model::BotStateAware,
+        r#const::{colours, discord::SELECT_MENU_OPTIONS_LIMIT, misc::SEARCH_RESULTS_TIMEOUT},
+    },
+    error::CommandResult,
+    ext::util::{PrettifiedTimestamp, PrettyTruncator},
+    gateway::ExpectedGuildIdAware,
+    lavalink::{
+        CorrectPlaylistInfo, CorrectTrackInfo, LavalinkAware, LoadSearch, PluginInfo, SearchResult,
+        SearchType,
+    },
+};
+
+#[derive(Clone, Copy)]
+enum ResultMenu {
+    Tracks,
+    Albums,
+    Artists,
+    Playlists,
+    Texts,
+}
+
+impl ResultMenu {
+    const fn custom_id(self) -> &'static str {
+        match self {
+            Self::Tracks => "tracks",
+            Self::Albums => "albums",
+            Self::Artists => "artists",
+            Self::Playlists => "playlists",
+            Self::Texts => "texts",
+        }
+    }
+
+    fn from_custom_id(custom_id: &str) -> Option<Self> {
+        [
+            Self::Tracks,
+            Self::Albums,
+            Self::Artists,
+            Self::Playlists,
+            Self::Texts,
+        ]
+        .into_iter()
+        .find(|m| m.custom_id() == custom_id)
+    }
+
+    const fn placeholder(self) -> &'static str {
+        match self {
+            Self::Tracks => "🎵 Add tracks...",
+            Self::Albums => "💿 Add albums...",
+            Self::Artists => "👤 Add top tracks from artists...",
+            Self::Playlists => "📚 Add playlists...",
+            Self::Texts => "🔎 Search for a suggestion instead...",
+        }
+    }
+}
+
+fn track_option(index: usize, track: &TrackData) -> SelectMenuOption {
+    let track_info = &track.info;
+    let track_length = PrettifiedTimestamp::from(Duration::milliseconds(track_info.length as i64));
+
+    SelectMenuOption {
+        default: false,
+        description: Some(format!(
+            "⌛{} 👤{}",
+            track_length,
+            track_info.corrected_author().pretty_truncate(80)
+        )),
+        emoji: None,
+        label: track_info
+            .corrected_title()
+            .pretty_truncate(100)
+            .into_owned(),
+        value: index.to_string(),
+    }
+}
+
+fn playlist_option(index: usize, playlist: &PlaylistData) -> SelectMenuOption {
+    let plugin_info = playlist.parse_plugin_info();
+    let author = plugin_info.as_ref().and_then(|i| i.author());
+    let total_tracks = plugin_info.as_ref().and_then(|i| i.total_tracks());
+
+    let description = match (author, total_tracks) {
+        (Some(author), Some(total_tracks)) => Some(format!(
+            "👤{} 📚{} tracks",
+            author.pretty_truncate(80),
+            total_tracks
+        )),
+        (Some(author), None) => Some(format!("👤{}", author.pretty_truncate(95))),
+        (None, Some(total_tracks)) => Some(format!("📚{total_tracks} tracks")),
+        (None, None) => None,
+    };
+
+    SelectMenuOption {
+        default: false,
+        description,
+        emoji: None,
+        label: playlist
+            .info
+            .corrected_name()
+            .pretty_truncate(100)
+            .into_owned(),
+        value: index.to_string(),
+    }
+}
+
+fn text_option(index: usize, text: &str) -> SelectMenuOption {
+    SelectMenuOption {
+        default: false,
+        description: None,
+        emoji: None,
+        label: text.pretty_truncate(100).into_owned(),
+        value: index.to_string(),
+    }
+}
+
+fn generate_row(menu: ResultMenu, options: Vec<SelectMenuOption>) -> Option<Component> {
+    if options.is_empty() {
+        return None;
+    }
+
+    let max_values = match menu {
+        ResultMenu::Texts => 1,
+        _ => options.len() as u8,
+    };
+    let select_menu = Component::SelectMenu(SelectMenu {
+        channel_types: None,
+        custom_id: menu.custom_id().to_owned(),
+        default_values: None,
+        disabled: false,
+        kind: SelectMenuType::Text,
+        max_values: Some(max_values),
+        min_values: Some(1),
+        options: Some(options),
+        placeholder: Some(menu.placeholder().to_owned()),
+    });
+    Some(Component::ActionRow(ActionRow {
+        components: vec![select_menu],
+    }))
+}
+
+fn generate_rows(result: &SearchResult) -> Vec<Component> {
+    let playlist_options = |playlists: &[PlaylistData]| -> Vec<_> {
+        playlists
+            .iter()
+            .take(SELECT_MENU_OPTIONS_LIMIT)
+            .enumerate()
+            .map(|(i, p)| playlist_option(i, p))
+            .collect()
+    };
+
+    [
+        (
+            ResultMenu::Tracks,
+            result
+                .tracks
+                .iter()
+                .take(SELECT_MENU_OPTIONS_LIMIT)
+                .enumerate()
+                .map(|(i, t)| track_option(i, t))
+                .collect::<Vec<_>>(),
+        ),
+        (ResultMenu::Albums, playlist_options(&result.albums)),
+        (ResultMenu::Artists, playlist_options(&result.artists)),
+        (ResultMenu::Playlists, playlist_options(&result.playlists)),
+        (
+            ResultMenu::Texts,
+            result
+                .texts
+                .iter()
+                .take(SELECT_MENU_OPTIONS_LIMIT)
+                .enumerate()
+                .map(|(i, t)| text_option(i, &t.text))
+                .collect::<Vec<_>>(),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(menu, options)| generate_row(menu, options))
+    .collect()
+}
+
+fn generate_embed(query: &str, result: &SearchResult) -> Result<Embed, EmbedValidationError> {
+    let counts = [
+        ("🎵", "tracks", result.tracks.len()),
+        ("💿", "albums", result.albums.len()),
+        ("👤", "artists", result.artists.len()),
+        ("📚", "playlists", result.playlists.len()),
+        ("🔎", "suggestions", result.texts.len()),
+    ]
+    .into_iter()
+    .filter(|(_, _, n)| *n != 0)
+    .map(|(emoji, kind, n)| format!("{emoji} `{n} {kind}`"))
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let embed = EmbedBuilder::new()
+        .title(format!(
+            "Search results for: {}",
+            query.pretty_truncate(200)
+        ))
+        .description(counts)
+        .footer(EmbedFooterBuilder::new(
+            "Pick from the menus below to add to the queue",
+        ))
+        .color(colours::EMBED_DEFAULT)
+        .validate()?
+        .build();
+    Ok(embed)
+}
+
+fn picked_queries(
+    result: &SearchResult,
+    menu: ResultMenu,
+    picked: impl Iterator<Item = usize>,
+) -> Vec<Box<str>> {
+    let playlist_url = |p: &PlaylistData| -> Option<Box<str>> {
+        p.parse_plugin_info().and_then(|i| i.url().map(Into::into))
+    };
+
+    picked
+        .filter_map(|i| match menu {
+            ResultMenu::Tracks => result
+                .tracks
+                .get(i)
+                .and_then(|t| t.info.uri.as_deref().map(Into::into)),
+            ResultMenu::Albums => result.albums.get(i).and_then(playlist_url),
+            ResultMenu::Artists => result.artists.get(i).and_then(playlist_url),
+            ResultMenu::Playlists => result.playlists.get(i).and_then(playlist_url),
+            ResultMenu::Texts => None,
+        })
+        .collect()
+}
+
+async fn search(
+    query: &str,
+    source: SearchSource,
+    ctx: &SlashCtx,
+) -> LavalinkResult<Option<SearchResult>> {
+    ctx.lavalink()
+        .load_search(
+            ctx.guild_id(),
+            &format!("{}{}", source.value(), query),
+            &SearchType::ALL,
+        )
+        .await
+}
+
+#[derive(CommandOption, CreateOption, Default, Clone, Copy)]
+enum SearchSource {
+    #[default]
+    #[option(name = "Spotify", value = "spsearch:")]
+    Spotify,
+    #[option(name = "Deezer", value = "dzsearch:")]
+    Deezer,
+    #[option(name = "Youtube", value = "ytsearch:")]
+    Youtube,
+    #[option(name = "Youtube Music", value = "ytmsearch:")]
+    YoutubeMusic,
+}
+
+/// Searches for tracks, albums, artists and playlists to add to the queue
+#[derive(CreateCommand, CommandModel)]
+#[command(name = "search", dm_permission = false)]
+pub struct Search {
+    /// What to search for?
+    query: String,
+    /// Search from where? (if not given, Spotify)
+    source: Option<SearchSource>,
+}
+
+impl BotSlashCommand for Search {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        let source = self.source.unwrap_or_default();
+        let mut query = self.query;
+
+        let Some(mut result) = search(&query, source, &ctx).await? else {
+            what!(format!("No results found for query: `{}`", query), ctx);
+        };
+
+        let message = ctx
+            .respond_embeds_and_components(
+                [generate_embed(&query, &result)?],
+                generate_rows(&result),
+            )
+            .await?
+            .model()
+            .await?;
+
+        let author_id = ctx.author_id();
+        let mut components = ctx
+            .bot()
+            .standby()
+            .wait_for_component_stream(message.id, move |i: &Interaction| {
+                i.author_id() == Some(author_id)
+            });
+
+        while let Ok(Some(mut interaction)) =
+            tokio::time::timeout(*SEARCH_RESULTS_TIMEOUT, components.next()).await
+        {
+            let Some(InteractionData::MessageComponent(data)) = interaction.data.take() else {
+                continue;
+            };
+            let Some(menu) = ResultMenu::from_custom_id(&data.custom_id) else {
+                continue;
+            };
+            let mut picked = data.values.iter().filter_map(|v| v.parse::<usize>().ok());
+
+            let mut component_ctx =
+                ctx.to_component_interaction(Box::new(InteractionCreate(interaction)));
+            if matches!(menu, ResultMenu::Texts) {
+                let Some(text) = picked.next().and_then(|i| result.texts.get(i)) else {
+                    continue;
+                };
+                let text = text.text.clone();
+
+                component_ctx.defer_update().await?;
+                let Some(new_result) = search(&text, source, &ctx).await? else {
+                    what_or_fol!(
+                        format!("No results found for query: `{}`", text),
+                        ?component_ctx
+                    );
+                    continue;
+                };
+                (query, result) = (text, new_result);
+
+                component_ctx
+                    .update_embeds_and_components(
+                        &[generate_embed(&query, &result)?],
+                        &generate_rows(&result),
+                    )
+                    .await?;
+                continue;
+            }
+
+            let queries = picked_queries(&result, menu, picked);
+            if queries.is_empty() {
+                bad!("None of the picked results can be added.", ?component_ctx);
+                continue;
+            }
+            if let Err(error) = super::play::play(&mut component_ctx, queries).await {
+                tracing::warn!(?error, "adding picked search results failed");
+                crit_or_fol!(
+                    format!("Something went wrong: ```rs\n{error:#?}```"),
+                    ?component_ctx
+                );
+            }
+        }
+
+        ctx.update_no_components().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,418 @@
+use lavalink_rs::model::track::TrackData;
+use twilight_mention::Mention;
+use twilight_model::{
+    channel::ChannelType,
+    guild::Permissions,
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker},
+        Id,
+    },
+};
+
+use crate::bot::{
+    command::check,
+    component::{config::music_channel::MusicChannelSettings, connection, tuning},
+    core::{
+        model::{
+            AuthorPermissionsAware, BotState, BotStateAware, CacheAware, HttpAware,
+            OwnedBotStateAware,
+        },
+        r#const::{
+            exit_code::{INVALID, NOTICE, NOT_FOUND, PROHIBITED, WARNING},
+            misc::{SONG_REQUEST_FEEDBACK_TIMEOUT, SONG_REQUEST_UP_NEXT_LIMIT},
+        },
+        traced,
+    },
+    error::{
+        command::check::UserAllowedError,
+        component::queue::{
+            play::{LoadTrackProcessManyError, QueryError},
+            HandleSongRequestError, UpdateSongRequestMessageError,
+        },
+        Cache as CacheError, LoadFailed as LoadFailedError,
+    },
+    ext::util::PrettyTruncator,
+    gateway::{message, ExpectedGuildIdAware, SenderAware},
+    lavalink::{CorrectTrackInfo, DelegateMethods, Lavalink, LavalinkAware},
+};
+
+use super::play::{extract_queries, guild_search_prefixes, LoadTrackContext};
+
+/// The text channel a guild treats every message in as a song request, as configured in `guild_configs`
+#[derive(Clone, Copy)]
+pub struct SongRequestChannel {
+    channel_id: Id<ChannelMarker>,
+    message_id: Id<MessageMarker>,
+}
+
+impl SongRequestChannel {
+    /// Reads through [`BotState::song_request_channels`], as this is checked for every message sent
+    async fn get(bot: &BotState, guild_id: Id<GuildMarker>) -> Result<Option<Self>, sqlx::Error> {
+        bot.song_request_channels()
+            .get_or_fetch(guild_id, || Self::fetch(bot, guild_id))
+            .await
+    }
+
+    async fn fetch(bot: &BotState, guild_id: Id<GuildMarker>) -> Result<Option<Self>, sqlx::Error> {
+        let config = sqlx::query!(
+            r"--sql
+            SELECT song_request_channel, song_request_message FROM guild_configs WHERE id = $1;",
+            guild_id.get() as i64
+        )
+        .fetch_one(bot.db())
+        .await?;
+
+        let (Some(channel_id), Some(message_id)) =
+            (config.song_request_channel, config.song_request_message)
+        else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            channel_id: Id::new(channel_id as u64),
+            message_id: Id::new(message_id as u64),
+        }))
+    }
+}
+
+/// Acts on behalf of the requester, so that requesting songs goes through the same checks as `/play`
+struct RequesterContext<'a> {
+    inner: &'a message::Context,
+    permissions: Permissions,
+}
+
+impl AuthorPermissionsAware for RequesterContext<'_> {
+    fn author_permissions(&self) -> Permissions {
+        self.permissions
+    }
+}
+
+impl BotStateAware for RequesterContext<'_> {
+    fn bot(&self) -> &BotState {
+        self.inner.bot()
+    }
+}
+
+impl ExpectedGuildIdAware for RequesterContext<'_> {
+    fn guild_id(&self) -> Id<GuildMarker> {
+        self.inner.guild_id()
+    }
+}
+
+/// Why the bot can't play in the connected channel, if it is muted or not a speaker of the stage
+fn suppressed_in(
+    ctx: &message::Context,
+    channel_id: Id<ChannelMarker>,
+) -> Result<Option<&'static str>, CacheError> {
+    let voice_state = ctx
+        .cache()
+        .voice_state(ctx.bot().user_id(), ctx.guild_id())
+        .ok_or(CacheError)?;
+    if voice_state.mute() {
+        return Ok(Some("the bot is currently server muted"));
+    }
+    let channel_type = ctx.cache().channel(channel_id).ok_or(CacheError)?.kind;
+    if voice_state.suppress() && channel_type == ChannelType::GuildStageVoice {
+        return Ok(Some(
+            "the bot is not currently a speaker in this stage channel",
+        ));
+    }
+    Ok(None)
+}
+
+fn track_text(track: &TrackData) -> String {
+    format!(
+        "`{}` by `{}`",
+        track.info.corrected_title().pretty_truncate(50),
+        track.info.corrected_author().pretty_truncate(30)
+    )
+}
+
+/// The content of the pinned song-request message, showing the current track and what is up next
+pub async fn live_message_content(lavalink: &Lavalink, guild_id: Id<GuildMarker>) -> String {
+    let header = "🎶 **Song Requests**\n-# Send a link, an audio file or a search query here to add it to the queue.";
+    let Some(data) = lavalink.get_player_data(guild_id) else {
+        return format!("{header}\n\n💤 Nothing is playing.");
+    };
+
+    let data_r = data.read().await;
+    let queue = data_r.queue();
+    let Some((current, index)) = queue.current_and_index() else {
+        return format!("{header}\n\n💤 Nothing is playing.");
+    };
+
+    let up_next = queue
+        .iter()
+        .skip(index + 1)
+        .take(SONG_REQUEST_UP_NEXT_LIMIT)
+        .enumerate()
+        .map(|(i, item)| format!("`{}.` {}", i + 1, track_text(item.track())))
+        .collect::<Vec<_>>();
+    let remaining = queue
+        .len()
+        .saturating_sub(index + 1 + SONG_REQUEST_UP_NEXT_LIMIT);
+
+    let mut content = format!(
+        "{header}\n\n▶️ {} — requested by {}",
+        track_text(current.track()),
+        current.requester().mention()
+    );
+    if !up_next.is_empty() {
+        content.push_str("\n\n**Up next**\n");
+        content.push_str(&up_next.join("\n"));
+    }
+    if remaining != 0 {
+        content.push_str(&format!("\n-# ...and `{remaining}` more"));
+    }
+    content
+}
+
+/// Brings the pinned song-request message up to date with the queue, if the guild has a song-request channel
+pub async fn update_live_message(
+    bot: &BotState,
+    guild_id: Id<GuildMarker>,
+) -> Result<(), UpdateSongRequestMessageError> {
+    let Some(song_requests) = SongRequestChannel::get(bot, guild_id).await? else {
+        return Ok(());
+    };
+
+    let content = live_message_content(bot.lavalink(), guild_id).await;
+    bot.http()
+        .update_message(song_requests.channel_id, song_requests.message_id)
+        .content(Some(&content))
+        .await?;
+    Ok(())
+}
+
+async fn send_feedback(
+    ctx: &message::Context,
+    content: String,
+) -> Result<(), HandleSongRequestError> {
+    let message = ctx
+        .http()
+        .create_message(ctx.inner.channel_id)
+        .content(&content)
+        .await?
+        .model()
+        .await?;
+
+    let bot = ctx.bot_owned();
+    traced::tokio_spawn(async move {
+        tokio::time::sleep(*SONG_REQUEST_FEEDBACK_TIMEOUT).await;
+        bot.http()
+            .delete_message(message.channel_id, message.id)
+            .await
+            .map(|_| ())
+    });
+    Ok(())
+}
+
+/// Treats a message sent in the song-request channel as a `/play` query, then deletes it
+pub async fn handle_message_create(ctx: &message::Context) -> Result<(), HandleSongRequestError> {
+    let guild_id = ctx.guild_id();
+    let Some(song_requests) = SongRequestChannel::get(ctx.bot(), guild_id).await? else {
+        return Ok(());
+    };
+    let message = &ctx.inner;
+    if message.channel_id != song_requests.channel_id {
+        return Ok(());
+    }
+
+    if let Err(error) = ctx
+        .http()
+        .delete_message(message.channel_id, message.id)
+        .await
+    {
+        tracing::warn!(?error, "deleting song request message failed");
+    }
+
+    let mut queries = extract_queries(message);
+    let content = message.content.trim();
+    if queries.is_empty() && !content.is_empty() {
+        queries.push(content.into());
+    }
+    if queries.is_empty() {
+        return Ok(());
+    }
+
+    let author_id = message.author.id;
+    let channel = ctx
+        .cache()
+        .channel(message.channel_id)
+        .ok_or(CacheError)?
+        .clone();
+    let requester = RequesterContext {
+        inner: ctx,
+        permissions: ctx
+            .cache()
+            .permissions()
+            .in_channel(author_id, message.channel_id)?,
+    };
+    let role_ids = message
+        .member
+        .as_ref()
+        .map(|m| m.roles.as_slice())
+        .unwrap_or_default();
+    match check::user_allowed_in_channel(guild_id, author_id, role_ids.iter(), &channel, &requester)
+        .await
+    {
+        Err(UserAllowedError::UserNotAllowed(_)) => {
+            return send_feedback(
+                ctx,
+                format!(
+                    "{PROHIBITED} {} You are not allowed to request songs here.",
+                    author_id.mention()
+                ),
+            )
+            .await;
+        }
+        result => result?,
+    }
+
+    let Some(voice_channel_id) = ctx
+        .cache()
+        .voice_state(author_id, guild_id)
+        .map(|v| v.channel_id())
+    else {
+        return send_feedback(
+            ctx,
+            format!(
+                "{NOTICE} {} Join a voice channel first to request songs.",
+                author_id.mention()
+            ),
+        )
+        .await;
+    };
+
+    let connected_channel_id = ctx
+        .lavalink()
+        .get_connection(guild_id)
+        .map(|c| c.channel_id);
+    match connected_channel_id {
+        Some(channel_id) if channel_id != voice_channel_id => {
+            return send_feedback(
+                ctx,
+                format!(
+                    "{NOTICE} {} Join {} first to request songs.",
+                    author_id.mention(),
+                    channel_id.mention()
+                ),
+            )
+            .await;
+        }
+        Some(channel_id) => {
+            if let Some(suppressed) = suppressed_in(ctx, channel_id)? {
+                return send_feedback(
+                    ctx,
+                    format!("{INVALID} Can't request songs as {suppressed}."),
+                )
+                .await;
+            }
+        }
+        None => {
+            let (channel_type, channel_parent_id) = ctx
+                .cache()
+                .channel(voice_channel_id)
+                .map(|c| (c.kind, c.parent_id))
+                .ok_or(CacheError)?;
+            if channel_type == ChannelType::GuildStageVoice
+                && check::user_is_stage_manager(&requester).is_err()
+            {
+                return send_feedback(
+                    ctx,
+                    format!(
+                        "{PROHIBITED} {} Joining {} requires being a **Stage Manager**.",
+                        author_id.mention(),
+                        voice_channel_id.mention()
+                    ),
+                )
+                .await;
+            }
+
+            let bot_permissions = ctx
+                .cache()
+                .permissions()
+                .in_channel(ctx.bot().user_id(), voice_channel_id)?;
+            if !bot_permissions.contains(Permissions::CONNECT) {
+                return send_feedback(
+                    ctx,
+                    format!(
+                        "{WARNING} Insufficient permissions to join {}.",
+                        voice_channel_id.mention()
+                    ),
+                )
+                .await;
+            }
+
+            match check::user_allowed_to_use(voice_channel_id, channel_parent_id, &requester).await
+            {
+                Err(UserAllowedError::UserNotAllowed(_)) => {
+                    return send_feedback(
+                        ctx,
+                        format!(
+                            "{PROHIBITED} {} You are not allowed to use the bot in {}.",
+                            author_id.mention(),
+                            voice_channel_id.mention()
+                        ),
+                    )
+                    .await;
+                }
+                result => result?,
+            }
+
+            let text_channel_id = MusicChannelSettings::get(ctx.bot(), guild_id)
+                .await?
+                .map_or(song_requests.channel_id, |m| m.channel_id());
+            connection::connect_without_interaction(
+                &ctx.bot_owned(),
+                ctx.sender(),
+                guild_id,
+                voice_channel_id,
+                text_channel_id,
+            )
+            .await?;
+        }
+    }
+
+    let search_prefixes = guild_search_prefixes(ctx.bot().db(), guild_id).await?;
+    let loaded = LoadTrackContext::new_via(ctx)
+        .with_search_prefixes(search_prefixes)
+        .process_many(queries)
+        .await;
+    let tracks = match loaded {
+        Ok(results) => Vec::<TrackData>::from(results),
+        Err(LoadTrackProcessManyError::Query(error)) => {
+            let query = match error {
+                QueryError::LoadFailed(LoadFailedError(query))
+                | QueryError::NoMatches(query)
+                | QueryError::SearchResult(query) => query,
+            };
+            return send_feedback(
+                ctx,
+                format!("{NOT_FOUND} No matches found for query: `{query}`"),
+            )
+            .await;
+        }
+        Err(LoadTrackProcessManyError::Lavalink(e)) => Err(e)?,
+    };
+
+    let lavalink = ctx.lavalink();
+    if lavalink.get_player_data(guild_id).is_none() {
+        let defaults = tuning::player_defaults(ctx.bot().db(), guild_id).await?;
+        lavalink.new_player_data(guild_id, defaults).await?;
+    }
+
+    let data = lavalink.player_data(guild_id);
+    let mut data_w = data.write().await;
+    let queue = data_w.queue_mut();
+    let idle = queue.current().is_none();
+    queue.enqueue(tracks, author_id);
+    let current = queue.current().map(|item| item.track().clone());
+    drop(data_w);
+
+    if let Some(track) = current.filter(|_| idle) {
+        lavalink.player(guild_id).play(&track).await?;
+    } else {
+        update_live_message(ctx.bot(), guild_id).await?;
+    }
+    Ok(())
+}
@@ -1,33 +1,210 @@
 mod equaliser;
 mod filter;
 mod speed;
+mod view;
 mod volume;
 
-use std::num::NonZeroU16;
+use std::{num::NonZeroU16, time::Duration};
 
-pub use equaliser::Equaliser;
+pub(super) use equaliser::resolve_gains as resolve_equaliser_gains;
+pub use equaliser::{Autocomplete as EqualiserAutocomplete, Equaliser};
 pub use filter::Filter;
-use lavalink_rs::{error::LavalinkResult, model::player::Filters};
+pub(super) use filter::FilterPreset;
+use lavalink_rs::{
+    error::LavalinkResult,
+    model::player::{Filters, Timescale},
+};
 pub use speed::Speed;
+use sqlx::{Pool, Postgres};
+use twilight_interactions::command::{CommandOption, CreateOption};
+use twilight_model::id::{marker::GuildMarker, Id};
+pub use view::Tuning;
 pub use volume::Volume;
 
 use crate::bot::{
     command::{
         check,
-        model::{Ctx, CtxKind},
+        macros::nope,
+        model::{CommandDataAware, Ctx, CtxKind, ModalCtx},
+        util::prompt_for_confirmation_with,
+        SlashCtx,
+    },
+    core::{
+        model::{BotStateAware, HttpAware, MessageResponse},
+        r#const::misc::{
+            DISTORTION_ESTIMATED_GAIN_DB, LOUDNESS_CONFIRMATION_THRESHOLD_DB,
+            LOUDNESS_REFUSAL_THRESHOLD_DB,
+        },
+    },
+    error::{
+        command::{Error as CommandError, RespondError},
+        component::tuning::HandleVoiceStateUpdateError,
+        CommandResult, TuningRestricted,
     },
-    core::model::{BotStateAware, HttpAware},
-    error::CommandResult,
     gateway::{voice, ExpectedGuildIdAware},
-    lavalink::{DelegateMethods, ExpectedPlayerAware, LavalinkAware},
+    lavalink::{
+        refresh_now_playing_message, AppliedFilterPreset, DelegateMethods, ExpectedPlayerAware,
+        ExpectedPlayerDataAware, FilterRevert, LavalinkAware, Pitch, PlayerDefaults,
+    },
 };
 
-#[inline]
-fn common_checks(ctx: &Ctx<impl CtxKind>) -> CommandResult {
+/// A tuning feature that can be disabled in `/config tuning`
+#[derive(CommandOption, CreateOption, Clone, Copy)]
+pub(super) enum TuningFeature {
+    #[option(name = "Channel Mix", value = "channel-mix")]
+    ChannelMix,
+    #[option(name = "Distortion", value = "distortion")]
+    Distortion,
+    #[option(name = "Equaliser", value = "equaliser")]
+    Equaliser,
+    #[option(name = "Karaoke", value = "karaoke")]
+    Karaoke,
+    #[option(name = "Low Pass", value = "low-pass")]
+    LowPass,
+    #[option(name = "Pitch", value = "pitch")]
+    Pitch,
+    #[option(name = "Filter Preset", value = "preset")]
+    FilterPreset,
+    #[option(name = "Rotation", value = "rotation")]
+    Rotation,
+    #[option(name = "Speed", value = "speed")]
+    Speed,
+    #[option(name = "Tremolo", value = "tremolo")]
+    Tremolo,
+    #[option(name = "Vibrato", value = "vibrato")]
+    Vibrato,
+}
+
+impl TuningFeature {
+    const ALL: [Self; 11] = [
+        Self::ChannelMix,
+        Self::Distortion,
+        Self::Equaliser,
+        Self::Karaoke,
+        Self::LowPass,
+        Self::Pitch,
+        Self::FilterPreset,
+        Self::Rotation,
+        Self::Speed,
+        Self::Tremolo,
+        Self::Vibrato,
+    ];
+
+    pub(super) const fn name(self) -> &'static str {
+        match self {
+            Self::ChannelMix => "Channel Mix",
+            Self::Distortion => "Distortion",
+            Self::Equaliser => "Equaliser",
+            Self::Karaoke => "Karaoke",
+            Self::LowPass => "Low Pass",
+            Self::Pitch => "Pitch",
+            Self::FilterPreset => "Filter Preset",
+            Self::Rotation => "Rotation",
+            Self::Speed => "Speed",
+            Self::Tremolo => "Tremolo",
+            Self::Vibrato => "Vibrato",
+        }
+    }
+
+    /// The feature a tuning command belongs to. Commands that turn a filter off never belong to any, so that disabled filters can still be turned off.
+    fn of_command(command_name: &str) -> Option<Self> {
+        let mut names = command_name.split(' ');
+        let name = match names.next()? {
+            "filter" => names.next()?,
+            name => name,
+        };
+        if names.last() == Some("off") {
+            return None;
+        }
+
+        Self::ALL.into_iter().find(|f| f.value() == name)
+    }
+}
+
+/// A tuning setting that can be bounded in `/config tuning bounds`
+#[derive(CommandOption, CreateOption, Clone, Copy)]
+pub(super) enum BoundedTuning {
+    #[option(name = "Speed", value = "speed")]
+    Speed,
+    #[option(name = "Pitch", value = "pitch")]
+    Pitch,
+}
+
+impl BoundedTuning {
+    pub(super) const fn name(self) -> &'static str {
+        match self {
+            Self::Speed => "Speed",
+            Self::Pitch => "Pitch",
+        }
+    }
+}
+
+async fn check_feature_enabled(ctx: &Ctx<impl CommandDataAware>) -> CommandResult {
+    let Some(feature) = TuningFeature::of_command(&ctx.command_name_full()) else {
+        return Ok(());
+    };
+
+    let disabled = sqlx::query!(
+        r#"--sql
+        SELECT $2 = ANY(disabled_tuning_features) AS "disabled!" FROM guild_configs WHERE id = $1;
+        "#,
+        ctx.guild_id().get() as i64,
+        feature.value(),
+    )
+    .fetch_one(ctx.db())
+    .await?
+    .disabled;
+
+    if disabled {
+        Err(TuningRestricted::Disabled(feature.name()))?;
+    }
+    Ok(())
+}
+
+/// Checks `value` against the bounds this guild set for `setting` in `/config tuning bounds`
+async fn check_bounds(
+    ctx: &Ctx<impl CtxKind>,
+    setting: BoundedTuning,
+    value: f64,
+) -> CommandResult {
+    let bounds = sqlx::query!(
+        r"--sql
+        SELECT min_speed, max_speed, min_pitch, max_pitch FROM guild_configs WHERE id = $1;
+        ",
+        ctx.guild_id().get() as i64,
+    )
+    .fetch_one(ctx.db())
+    .await?;
+
+    let (min, max) = match setting {
+        BoundedTuning::Speed => (bounds.min_speed, bounds.max_speed),
+        BoundedTuning::Pitch => (bounds.min_pitch, bounds.max_pitch),
+    };
+    if min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max) {
+        Err(TuningRestricted::OutOfBounds {
+            setting: setting.name(),
+            min,
+            max,
+        })?;
+    }
+    Ok(())
+}
+
+/// Checks the speed and pitch `timescale` effectively plays at against the bounds, as its rate changes both at once
+async fn check_timescale_bounds(ctx: &Ctx<impl CtxKind>, timescale: &Timescale) -> CommandResult {
+    let rate = timescale.rate.unwrap_or(1.);
+    let speed = timescale.speed.unwrap_or(1.) * rate;
+    let pitch = timescale.pitch.unwrap_or(1.) * rate;
+    check_bounds(ctx, BoundedTuning::Speed, speed).await?;
+    check_bounds(ctx, BoundedTuning::Pitch, pitch).await
+}
+
+async fn common_checks(ctx: &Ctx<impl CommandDataAware>) -> CommandResult {
     check::user_is_dj(ctx)?;
     check::in_voice(ctx)?;
     check::not_suppressed(ctx)?;
     check::player_exist(ctx)?;
+    check_feature_enabled(ctx).await?;
 
     Ok(())
 }
@@ -64,9 +241,213 @@ async fn set_filter(
     Ok(())
 }
 
+/// Snapshots the player's tuning if `duration` is given, so that the change about to be made can revert after that many seconds
+async fn snapshot_for_revert(
+    ctx: &Ctx<impl CtxKind>,
+    duration: Option<i64>,
+) -> LavalinkResult<Option<FilterRevert>> {
+    let Some(secs) = duration else {
+        return Ok(None);
+    };
+
+    let after = Duration::from_secs(secs as u64);
+    FilterRevert::snapshot(ctx.player(), ctx.player_data(), after)
+        .await
+        .map(Some)
+}
+
+/// Schedules the revert from [`snapshot_for_revert`], returning a note to append to the response
+async fn schedule_revert(revert: Option<FilterRevert>) -> LavalinkResult<String> {
+    let Some(revert) = revert else {
+        return Ok(String::new());
+    };
+
+    let note = format!(
+        " (`Reverts in {}s or when this track ends`)",
+        revert.after().as_secs()
+    );
+    revert.schedule().await?;
+    Ok(note)
+}
+
+/// Roughly estimates how much louder than unity playback at `volume` with `filters` would be, in decibels
+fn estimate_gain_db(volume: NonZeroU16, filters: &Filters) -> f64 {
+    let volume_db = 20. * (f64::from(volume.get()) / 100.).log10();
+    let equaliser_db = filters
+        .equalizer
+        .as_ref()
+        .and_then(|bands| bands.iter().map(|b| b.gain).max_by(f64::total_cmp))
+        .map_or(0., |gain| gain.max(0.) * equaliser::DB_PER_GAIN);
+    let distortion_db = filters.distortion.as_ref().map_or(0., |d| {
+        let scale = [d.sin_scale, d.cos_scale, d.tan_scale, d.scale]
+            .into_iter()
+            .flatten()
+            .map(f64::abs)
+            .fold(1., f64::max);
+        20_f64.mul_add(scale.log10(), DISTORTION_ESTIMATED_GAIN_DB)
+    });
+
+    volume_db + equaliser_db + distortion_db
+}
+
+/// The context to respond with after [`guard_loudness`], as the confirmation modal takes over the interaction once shown
+enum LoudnessGuarded {
+    Unprompted(SlashCtx),
+    Confirmed(ModalCtx),
+}
+
+impl LoudnessGuarded {
+    async fn respond(
+        &mut self,
+        content: impl Into<String> + Send,
+    ) -> Result<MessageResponse, RespondError> {
+        match self {
+            Self::Unprompted(ctx) => ctx.respond(content).await,
+            Self::Confirmed(ctx) => ctx.respond(content).await,
+        }
+    }
+}
+
+/// Refuses playback at `volume` with `filters` if it would be dangerously loud, and asks for confirmation if it would be very loud
+async fn guard_loudness(
+    mut ctx: SlashCtx,
+    volume: NonZeroU16,
+    filters: &Filters,
+) -> Result<Option<LoudnessGuarded>, CommandError> {
+    let gain_db = estimate_gain_db(volume, filters);
+
+    if gain_db > LOUDNESS_REFUSAL_THRESHOLD_DB {
+        nope!(
+            format!(
+                "This would make playback dangerously loud (`~+{gain_db:.0}dB`). Lower the volume, equaliser or distortion first."
+            ),
+            ?ctx
+        );
+        return Ok(None);
+    }
+    if gain_db > LOUDNESS_CONFIRMATION_THRESHOLD_DB {
+        let label = format!("This will be very loud (+{gain_db:.0}dB). Are you sure?");
+        let ctx = prompt_for_confirmation_with(ctx, label).await?;
+        return Ok(Some(LoudnessGuarded::Confirmed(ctx)));
+    }
+    Ok(Some(LoudnessGuarded::Unprompted(ctx)))
+}
+
+/// Like [`set_filter`], but guards the updated filters with [`guard_loudness`] before setting them
+async fn set_filter_guarded(
+    ctx: SlashCtx,
+    update: impl UpdateFilter + Send + Sync,
+) -> Result<Option<LoudnessGuarded>, CommandError> {
+    let player = ctx.player();
+    let filters = update.apply(player.get_player().await?.filters.unwrap_or_default());
+    let volume = ctx.player_data().read().await.volume();
+
+    let Some(ctx) = guard_loudness(ctx, volume, &filters).await? else {
+        return Ok(None);
+    };
+    player.set_filters(filters).await?;
+    Ok(Some(ctx))
+}
+
+/// Resolves the guild's configured default volume and filter/equaliser profile for a new player, leaving out the filters if [`guard_loudness`] would have asked to confirm them
+pub async fn player_defaults(
+    db: &Pool<Postgres>,
+    guild_id: Id<GuildMarker>,
+) -> Result<PlayerDefaults, sqlx::Error> {
+    let config = sqlx::query!(
+        r"--sql
+        SELECT default_volume, max_volume, default_filter_preset, default_equaliser_preset
+        FROM guild_configs WHERE id = $1;
+        ",
+        guild_id.get() as i64,
+    )
+    .fetch_one(db)
+    .await?;
+
+    let volume = NonZeroU16::new(config.default_volume.min(config.max_volume) as u16)
+        .expect("volumes are non-zero");
+
+    let mut filters = None::<Filters>;
+    if let Some(name) = config.default_equaliser_preset {
+        if let Some(gains) = resolve_equaliser_gains(db, guild_id, &name).await? {
+            filters = Some(Filters {
+                equalizer: Some(equaliser::bands_from_gains(gains)),
+                ..Default::default()
+            });
+        }
+    }
+
+    let filter_preset = config
+        .default_filter_preset
+        .as_deref()
+        .and_then(FilterPreset::from_name)
+        .map(|preset| {
+            let replaced = filters.clone().unwrap_or_default();
+            AppliedFilterPreset::new(preset.value(), preset.filters(), replaced, Pitch::default())
+        });
+    if let Some(ref preset) = filter_preset {
+        filters = Some(preset.apply_to(filters.unwrap_or_default()));
+    }
+
+    // there is no one to confirm very loud defaults when a player is created, so start without them instead
+    if let Some(gain_db) = filters
+        .as_ref()
+        .map(|f| estimate_gain_db(volume, f))
+        .filter(|&gain_db| gain_db > LOUDNESS_CONFIRMATION_THRESHOLD_DB)
+    {
+        tracing::warn!(
+            ?guild_id,
+            gain_db,
+            "default filters are too loud, not applying them"
+        );
+        return Ok(PlayerDefaults::new(volume, None, None));
+    }
+
+    Ok(PlayerDefaults::new(volume, filters, filter_preset))
+}
+
+async fn pause_when_muted(ctx: &voice::Context) -> LavalinkResult<bool> {
+    let guild_id = ctx.guild_id();
+    let (Some(player), Some(data)) = (
+        ctx.lavalink().get_player_context(guild_id),
+        ctx.lavalink().get_player_data(guild_id),
+    ) else {
+        return Ok(false);
+    };
+    let state = player.get_player().await?;
+    if state.track.is_none() || state.paused {
+        return Ok(false);
+    }
+
+    player.set_pause(true).await?;
+    data.write().await.set_paused_when_muted();
+    Ok(true)
+}
+
+async fn resume_on_unmute(ctx: &voice::Context) -> LavalinkResult<bool> {
+    let guild_id = ctx.guild_id();
+    let (Some(player), Some(data)) = (
+        ctx.lavalink().get_player_context(guild_id),
+        ctx.lavalink().get_player_data(guild_id),
+    ) else {
+        return Ok(false);
+    };
+    if !data.write().await.take_paused_when_muted() {
+        return Ok(false);
+    }
+
+    player.set_pause(false).await?;
+    Ok(true)
+}
+
 #[tracing::instrument(skip_all, name = "voice_state_update")]
-pub async fn handle_voice_state_update(ctx: &voice::Context) -> Result<(), twilight_http::Error> {
+pub async fn handle_voice_state_update(
+    ctx: &voice::Context,
+) -> Result<(), HandleVoiceStateUpdateError> {
     let bot = ctx.bot();
+    if ctx.inner.user_id != bot.user_id() {
+        return Ok(());
+    }
     let guild_id = ctx.guild_id();
     let lavalink = bot.lavalink();
     let Some(mut connection) = lavalink.get_connection_mut(guild_id) else {
@@ -74,23 +455,122 @@ pub async fn handle_voice_state_update(ctx: &voice::Context) -> Result<(), twili
     };
 
     let state_mute = ctx.inner.mute;
-    if connection.mute != state_mute {
-        connection.mute = state_mute;
-
-        let emoji = volume::volume_emoji(if state_mute {
-            None
-        } else if let Some(d) = lavalink.get_player_data(guild_id) {
-            Some(d.read().await.volume())
-        } else {
-            Some(NonZeroU16::new(100).expect("volume is non-zero"))
-        });
-        let describe = if state_mute { "muted" } else { "unmuted" };
+    if connection.mute == state_mute {
+        return Ok(());
+    }
+    connection.mute = state_mute;
+    let text_channel_id = connection.text_channel_id;
+    drop(connection);
+
+    let emoji = volume::volume_emoji(if state_mute {
+        None
+    } else if let Some(d) = lavalink.get_player_data(guild_id) {
+        Some(d.read().await.volume())
+    } else {
+        Some(NonZeroU16::new(100).expect("volume is non-zero"))
+    });
+    let (describe, action) = if state_mute {
+        let paused = pause_when_muted(ctx).await?;
+        ("muted", paused.then_some("; Paused until unmuted"))
+    } else {
+        let resumed = resume_on_unmute(ctx).await?;
+        ("unmuted", resumed.then_some("; Resumed"))
+    };
+
+    tracing::warn!("guild {} {} forcefully", guild_id, describe);
+    bot.http()
+        .create_message(text_channel_id)
+        .content(&format!(
+            "{emoji} `(Bot was forcefully {describe}{})`",
+            action.unwrap_or_default()
+        ))
+        .await?;
 
-        tracing::warn!("guild {} {} forcefully", guild_id, describe);
-        bot.http()
-            .create_message(connection.text_channel_id)
-            .content(&format!("{emoji} `(Bot was forcefully {describe})`"))
-            .await?;
+    if let Err(error) = refresh_now_playing_message(bot, guild_id).await {
+        tracing::warn!(?error, "refreshing now playing message failed");
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU16;
+
+    use lavalink_rs::model::player::{Distortion, Equalizer, Filters};
+    use rstest::rstest;
+
+    use super::estimate_gain_db;
+
+    const TOLERANCE: f64 = 0.01;
+
+    fn volume(percent: u16) -> NonZeroU16 {
+        NonZeroU16::new(percent).expect("volume is non-zero")
+    }
+
+    fn equaliser(gains: &[f64]) -> Filters {
+        Filters {
+            equalizer: Some(
+                (0..)
+                    .zip(gains)
+                    .map(|(band, &gain)| Equalizer { band, gain })
+                    .collect(),
+            ),
+            ..Filters::default()
+        }
+    }
+
+    fn distortion(scale: Option<f64>, sin_scale: Option<f64>) -> Filters {
+        Filters {
+            distortion: Some(Distortion {
+                scale,
+                sin_scale,
+                ..Distortion::default()
+            }),
+            ..Filters::default()
+        }
+    }
+
+    #[rstest]
+    #[case(100, 0.)]
+    #[case(200, 6.02)]
+    #[case(50, -6.02)]
+    #[case(1_000, 20.)]
+    fn estimate_gain_db_of_volume(#[case] percent: u16, #[case] expected: f64) {
+        let gain = estimate_gain_db(volume(percent), &Filters::default());
+        assert!((gain - expected).abs() < TOLERANCE);
+    }
+
+    #[rstest]
+    #[case(&[], 0.)]
+    #[case(&[-0.25, -0.1], 0.)]
+    #[case(&[0.5, -0.25], 8.)]
+    #[case(&[0.25, 1.0, 0.5], 16.)]
+    fn estimate_gain_db_of_equaliser(#[case] gains: &[f64], #[case] expected: f64) {
+        let gain = estimate_gain_db(volume(100), &equaliser(gains));
+        assert!((gain - expected).abs() < TOLERANCE);
+    }
+
+    #[rstest]
+    #[case(None, None, 6.)]
+    #[case(Some(0.5), None, 6.)]
+    #[case(Some(2.), None, 12.02)]
+    #[case(Some(-2.), Some(10.), 26.)]
+    fn estimate_gain_db_of_distortion(
+        #[case] scale: Option<f64>,
+        #[case] sin_scale: Option<f64>,
+        #[case] expected: f64,
+    ) {
+        let gain = estimate_gain_db(volume(100), &distortion(scale, sin_scale));
+        assert!((gain - expected).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn estimate_gain_db_adds_up() {
+        let filters = Filters {
+            distortion: Some(Distortion::default()),
+            ..equaliser(&[1.0])
+        };
+        let gain = estimate_gain_db(volume(200), &filters);
+        assert!((gain - 28.02).abs() < TOLERANCE);
+    }
+}
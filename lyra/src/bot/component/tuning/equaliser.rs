@@ -1,12 +1,43 @@
 mod custom;
+mod delete;
+mod import;
 mod off;
 mod preset;
+mod save;
 
 use lavalink_rs::model::player::{Equalizer, Filters};
 use lyra_proc::BotCommandGroup;
+pub(in crate::bot::component) use preset::resolve_gains;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
-const EQUALISER_N: usize = 15;
+use crate::bot::{
+    command::{model::BotAutocomplete, AutocompleteCtx},
+    error::command::AutocompleteResult,
+};
+
+pub(super) const EQUALISER_N: usize = 15;
+/// See `preset/equalisers.toml`: -0.25 is -4dB and 1.0 is +16dB
+pub(super) const DB_PER_GAIN: f64 = 16.;
+/// The center frequencies of each band, in Hz.
+pub(super) const BAND_FREQUENCIES: [u16; EQUALISER_N] = [
+    25, 40, 63, 100, 160, 250, 400, 630, 1_000, 1_600, 2_500, 4_000, 6_300, 10_000, 16_000,
+];
+
+/// Collects the gains of every band, treating missing bands as unchanged
+pub(super) fn gains_from_bands(bands: &[Equalizer]) -> [f64; EQUALISER_N] {
+    let mut gains = [SetEqualiser::DEFAULT_GAIN; EQUALISER_N];
+    for band in bands {
+        if let Some(gain) = gains.get_mut(band.band as usize) {
+            *gain = band.gain;
+        }
+    }
+    gains
+}
+
+/// Converts gains into the bands lavalink expects, the inverse of [`gains_from_bands`]
+pub(super) fn bands_from_gains(gains: [f64; EQUALISER_N]) -> Vec<Equalizer> {
+    SetEqualiser::from(gains).into()
+}
 
 struct SetEqualiser([Equalizer; EQUALISER_N]);
 
@@ -27,10 +58,25 @@ impl SetEqualiser {
     }
 }
 
+impl From<[f64; EQUALISER_N]> for SetEqualiser {
+    fn from(value: [f64; EQUALISER_N]) -> Self {
+        Self(core::array::from_fn(|i| Equalizer {
+            band: i as u8,
+            gain: value[i],
+        }))
+    }
+}
+
+impl From<SetEqualiser> for Vec<Equalizer> {
+    fn from(value: SetEqualiser) -> Self {
+        value.0.into()
+    }
+}
+
 impl super::UpdateFilter for Option<SetEqualiser> {
     fn apply(self, filter: Filters) -> Filters {
         Filters {
-            equalizer: self.map(|f| f.0.into()),
+            equalizer: self.map(Into::into),
             ..filter
         }
     }
@@ -45,4 +91,27 @@ pub enum Equaliser {
     Custom(Box<custom::Custom>),
     #[command(name = "off")]
     Off(off::Off),
+    #[command(name = "import")]
+    Import(import::Import),
+    #[command(name = "save")]
+    Save(save::Save),
+    #[command(name = "delete")]
+    Delete(delete::Delete),
+}
+
+#[derive(CommandModel)]
+pub enum Autocomplete {
+    #[command(name = "preset")]
+    Preset(preset::Autocomplete),
+    #[command(name = "delete")]
+    Delete(delete::Autocomplete),
+}
+
+impl BotAutocomplete for Autocomplete {
+    async fn execute(self, ctx: AutocompleteCtx) -> AutocompleteResult {
+        match self {
+            Self::Preset(a) => a.execute(ctx).await,
+            Self::Delete(a) => a.execute(ctx).await,
+        }
+    }
 }
@@ -2,7 +2,10 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::bot::{
     command::macros::{bad, out},
-    component::tuning::{common_checks, equaliser::SetEqualiser, set_filter},
+    component::tuning::{
+        common_checks, equaliser::SetEqualiser, schedule_revert, set_filter_guarded,
+        snapshot_for_revert,
+    },
 };
 
 /// Enable the player equaliser with custom settings.
@@ -55,11 +58,15 @@ pub struct Custom {
     /// How much gain for band 15? [Default: 0, Muted: -0.25, Doubled: 0.25] (If not given, 0)
     #[command(min_value = -0.25, max_value = 1.0)]
     band_15: Option<f64>,
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
 }
 
 impl crate::bot::command::model::BotSlashCommand for Custom {
     async fn run(self, mut ctx: crate::bot::command::SlashCtx) -> crate::bot::error::CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         let equaliser = [
             self.band_1,
@@ -89,7 +96,13 @@ impl crate::bot::command::model::BotSlashCommand for Custom {
             );
         };
 
-        set_filter(&ctx, Some(filter)).await?;
-        out!("🎛️🟢 Enabled player equaliser (**`Custom Settings`**)", ctx);
+        let Some(mut ctx) = set_filter_guarded(ctx, Some(filter)).await? else {
+            return Ok(());
+        };
+        let reverting = schedule_revert(revert).await?;
+        out!(
+            format!("🎛️🟢 Enabled player equaliser (**`Custom Settings`**){reverting}"),
+            ctx
+        );
     }
 }
@@ -0,0 +1,97 @@
+use twilight_interactions::command::{AutocompleteValue, CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::{
+        check,
+        macros::{out, what},
+        model::BotAutocomplete,
+        AutocompleteCtx,
+    },
+    core::r#const::discord::COMMAND_CHOICES_LIMIT,
+    error::command::AutocompleteResult,
+    gateway::ExpectedGuildIdAware,
+};
+
+/// Delete an equaliser preset saved in this server
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "delete")]
+pub struct Delete {
+    /// Which saved preset to delete?
+    #[command(autocomplete = true)]
+    name: String,
+}
+
+impl crate::bot::command::model::BotSlashCommand for Delete {
+    async fn run(self, mut ctx: crate::bot::command::SlashCtx) -> crate::bot::error::CommandResult {
+        check::user_is_dj(&ctx)?;
+
+        let guild_id = ctx.guild_id().get() as i64;
+        let res = sqlx::query!(
+            r"--sql
+            DELETE FROM equaliser_presets WHERE guild = $1 AND name = $2;
+            ",
+            guild_id,
+            self.name,
+        )
+        .execute(ctx.db())
+        .await?;
+
+        if res.rows_affected() == 0 {
+            what!(
+                format!(
+                    "No saved equaliser preset named **`{}`** exists.",
+                    self.name
+                ),
+                ctx
+            );
+        }
+
+        let unset_default = sqlx::query!(
+            r"--sql
+            UPDATE guild_configs SET default_equaliser_preset = NULL
+            WHERE id = $1 AND default_equaliser_preset = $2;
+            ",
+            guild_id,
+            self.name,
+        )
+        .execute(ctx.db())
+        .await?
+        .rows_affected();
+        let unset_default_text = if unset_default == 0 {
+            ""
+        } else {
+            "\n-# New players will no longer start with it."
+        };
+
+        out!(
+            format!(
+                "🎛️🗑️ Deleted the saved equaliser preset **`{}`**{unset_default_text}",
+                self.name
+            ),
+            ctx
+        );
+    }
+}
+
+#[derive(CommandModel)]
+#[command(autocomplete = true)]
+pub struct Autocomplete {
+    name: AutocompleteValue<String>,
+}
+
+impl BotAutocomplete for Autocomplete {
+    async fn execute(self, mut ctx: AutocompleteCtx) -> AutocompleteResult {
+        let AutocompleteValue::Focused(focused) = self.name else {
+            unreachable!("exactly one option is focused");
+        };
+
+        let choices = super::preset::saved_preset_names(&ctx)
+            .await?
+            .into_iter()
+            .filter(|n| super::preset::matches_preset_name(n, &focused))
+            .map(super::preset::generate_saved_preset_choice)
+            .take(COMMAND_CHOICES_LIMIT)
+            .collect();
+        Ok(ctx.autocomplete(choices).await?)
+    }
+}
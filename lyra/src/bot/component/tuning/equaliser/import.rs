@@ -0,0 +1,454 @@
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Attachment;
+
+use crate::bot::{
+    command::macros::{bad, out},
+    component::tuning::{
+        common_checks,
+        equaliser::{SetEqualiser, BAND_FREQUENCIES, DB_PER_GAIN, EQUALISER_N},
+        schedule_revert, set_filter_guarded, snapshot_for_revert,
+    },
+    core::r#const::misc::{EQUALISER_IMPORT_FILE_SIZE_LIMIT, EQUALISER_IMPORT_TIMEOUT},
+    error::component::tuning::equaliser::ParseCurveError,
+};
+
+const MIN_GAIN: f64 = -0.25;
+const MAX_GAIN: f64 = 1.0;
+const SAMPLE_RATE: f64 = 48_000.;
+const DEFAULT_SHELF_Q: f64 = FRAC_1_SQRT_2;
+
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 3],
+}
+
+/// Coefficients and responses from the Audio EQ Cookbook, written out as-is for readability
+#[allow(clippy::suboptimal_flops)]
+impl Biquad {
+    fn new(kind: &str, frequency: f64, gain: f64, q: f64) -> Result<Self, ParseCurveError> {
+        let amp = 10_f64.powf(gain / 40.);
+        let w0 = 2. * PI * frequency / SAMPLE_RATE;
+        let (sin, cos) = w0.sin_cos();
+        let alpha = sin / (2. * q);
+        let shelf = 2. * amp.sqrt() * alpha;
+
+        let (b, a) = match kind {
+            "PK" | "PEQ" => (
+                [1. + alpha * amp, -2. * cos, 1. - alpha * amp],
+                [1. + alpha / amp, -2. * cos, 1. - alpha / amp],
+            ),
+            "LS" | "LSC" | "LSQ" => (
+                [
+                    amp * ((amp + 1.) - (amp - 1.) * cos + shelf),
+                    2. * amp * ((amp - 1.) - (amp + 1.) * cos),
+                    amp * ((amp + 1.) - (amp - 1.) * cos - shelf),
+                ],
+                [
+                    (amp + 1.) + (amp - 1.) * cos + shelf,
+                    -2. * ((amp - 1.) + (amp + 1.) * cos),
+                    (amp + 1.) + (amp - 1.) * cos - shelf,
+                ],
+            ),
+            "HS" | "HSC" | "HSQ" => (
+                [
+                    amp * ((amp + 1.) + (amp - 1.) * cos + shelf),
+                    -2. * amp * ((amp - 1.) + (amp + 1.) * cos),
+                    amp * ((amp + 1.) + (amp - 1.) * cos - shelf),
+                ],
+                [
+                    (amp + 1.) - (amp - 1.) * cos + shelf,
+                    2. * ((amp - 1.) - (amp + 1.) * cos),
+                    (amp + 1.) - (amp - 1.) * cos - shelf,
+                ],
+            ),
+            _ => return Err(ParseCurveError::UnsupportedFilter(kind.into())),
+        };
+        Ok(Self { b, a })
+    }
+
+    fn response_at(&self, frequency: f64) -> f64 {
+        fn magnitude_squared(c: [f64; 3], w: f64) -> f64 {
+            let re = c[0] + c[1] * w.cos() + c[2] * (2. * w).cos();
+            let im = c[1] * w.sin() + c[2] * (2. * w).sin();
+            re.mul_add(re, im * im)
+        }
+
+        let w = 2. * PI * frequency / SAMPLE_RATE;
+        10. * (magnitude_squared(self.b, w) / magnitude_squared(self.a, w)).log10()
+    }
+}
+
+enum Curve {
+    Graphic(Vec<(f64, f64)>),
+    Parametric(Vec<Biquad>),
+}
+
+impl Curve {
+    fn parse(text: &str) -> Result<Self, ParseCurveError> {
+        if let Some(points) = text
+            .lines()
+            .find_map(|l| l.trim().strip_prefix("GraphicEQ:"))
+        {
+            return Self::parse_graphic(points);
+        }
+
+        // the preamp only prevents clipping, so it is ignored as lavalink gains are relative anyway
+        let filters = text
+            .lines()
+            .filter_map(|l| l.trim().strip_prefix("Filter"))
+            .filter_map(|l| Self::parse_filter(l).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+        if filters.is_empty() {
+            return Err(ParseCurveError::Unrecognised);
+        }
+        Ok(Self::Parametric(filters))
+    }
+
+    fn parse_graphic(points: &str) -> Result<Self, ParseCurveError> {
+        let mut points = points
+            .split(';')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(|p| {
+                let mut values = p.split_whitespace().map(str::parse::<f64>);
+                match (values.next(), values.next(), values.next()) {
+                    (Some(Ok(frequency)), Some(Ok(gain)), None) if frequency > 0. => {
+                        Ok((frequency, gain))
+                    }
+                    _ => Err(ParseCurveError::GraphicPoint(p.into())),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if points.is_empty() {
+            return Err(ParseCurveError::Unrecognised);
+        }
+
+        points.sort_unstable_by(|(f1, _), (f2, _)| f1.total_cmp(f2));
+        Ok(Self::Graphic(points))
+    }
+
+    fn parse_filter(line: &str) -> Result<Option<Biquad>, ParseCurveError> {
+        let invalid = || ParseCurveError::Filter(line.trim().into());
+
+        let (_, filter) = line.split_once(':').ok_or_else(invalid)?;
+        let tokens = filter.split_whitespace().collect::<Vec<_>>();
+        match tokens.first() {
+            Some(&"ON") => {}
+            Some(&"OFF") => return Ok(None),
+            _ => return Err(invalid()),
+        }
+        let kind = tokens.get(1).ok_or_else(invalid)?;
+        let value_of = |key: &str| {
+            tokens
+                .iter()
+                .position(|&t| t == key)
+                .and_then(|i| tokens.get(i + 1))
+                .map(|v| v.parse::<f64>().map_err(|_| invalid()))
+                .transpose()
+        };
+
+        let frequency = value_of("Fc")?.ok_or_else(invalid)?;
+        let gain = value_of("Gain")?.ok_or_else(invalid)?;
+        let q = value_of("Q")?.unwrap_or(DEFAULT_SHELF_Q);
+        if frequency <= 0. || frequency >= SAMPLE_RATE / 2. || q <= 0. {
+            return Err(invalid());
+        }
+
+        Biquad::new(kind, frequency, gain, q).map(Some)
+    }
+
+    /// Interpolates linearly over log-frequency for graphic curves, and sums every filter's response for parametric ones
+    fn gain_at(&self, frequency: f64) -> f64 {
+        match self {
+            Self::Graphic(points) => {
+                let upper = points.partition_point(|&(f, _)| f < frequency);
+                match (upper.checked_sub(1).map(|i| points[i]), points.get(upper)) {
+                    (Some((f1, g1)), Some(&(f2, g2))) => {
+                        let t = (frequency / f1).ln() / (f2 / f1).ln();
+                        (g2 - g1).mul_add(t, g1)
+                    }
+                    (None, Some(&(_, g))) | (Some((_, g)), None) => g,
+                    (None, None) => unreachable!("graphic curves are non-empty"),
+                }
+            }
+            Self::Parametric(filters) => filters.iter().map(|f| f.response_at(frequency)).sum(),
+        }
+    }
+
+    /// Samples the curve at each band's center frequency, returning the band gains and whether any of them had to be clamped
+    fn band_gains(&self) -> ([Option<f64>; EQUALISER_N], bool) {
+        let mut clamped = false;
+        let gains = core::array::from_fn(|i| {
+            let gain = self.gain_at(f64::from(BAND_FREQUENCIES[i])) / DB_PER_GAIN;
+            clamped |= !(MIN_GAIN..=MAX_GAIN).contains(&gain);
+            Some(gain.clamp(MIN_GAIN, MAX_GAIN))
+        });
+        (gains, clamped)
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref CLIENT: reqwest::Client = reqwest::Client::builder()
+        .timeout(*EQUALISER_IMPORT_TIMEOUT)
+        .build()
+        .expect("client is valid");
+}
+
+/// Downloads the file, giving up as soon as it turns out to be larger than the limit, whatever size the attachment claimed
+async fn download(url: &str) -> Result<Option<String>, reqwest::Error> {
+    let mut response = CLIENT.get(url).send().await?.error_for_status()?;
+    if response
+        .content_length()
+        .is_some_and(|len| len > EQUALISER_IMPORT_FILE_SIZE_LIMIT)
+    {
+        return Ok(None);
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if (body.len() + chunk.len()) as u64 > EQUALISER_IMPORT_FILE_SIZE_LIMIT {
+            return Ok(None);
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(Some(String::from_utf8_lossy(&body).into_owned()))
+}
+
+/// Enable the player equaliser from an EqualizerAPO `GraphicEQ` or AutoEQ parametric file
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "import")]
+pub struct Import {
+    /// Which equaliser file?
+    file: Attachment,
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
+
+impl crate::bot::command::model::BotSlashCommand for Import {
+    async fn run(self, mut ctx: crate::bot::command::SlashCtx) -> crate::bot::error::CommandResult {
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
+
+        let text = if self.file.size > EQUALISER_IMPORT_FILE_SIZE_LIMIT {
+            None
+        } else {
+            download(&self.file.url).await?
+        };
+        let Some(text) = text else {
+            bad!(
+                format!(
+                    "Equaliser files must not be larger than `{} KiB`.",
+                    EQUALISER_IMPORT_FILE_SIZE_LIMIT >> 10
+                ),
+                ctx
+            );
+        };
+        let curve = match Curve::parse(&text) {
+            Ok(curve) => curve,
+            Err(e) => {
+                bad!(format!("Importing the equaliser failed: {e}."), ctx);
+            }
+        };
+
+        let (gains, clamped) = curve.band_gains();
+        let Some(update) = SetEqualiser::new(gains) else {
+            bad!("The imported equaliser does not change any band.", ctx);
+        };
+
+        let Some(mut ctx) = set_filter_guarded(ctx, Some(update)).await? else {
+            return Ok(());
+        };
+        let note = if clamped {
+            " `(Some bands were clamped to -4dB..+16dB)`"
+        } else {
+            ""
+        };
+        let reverting = schedule_revert(revert).await?;
+        out!(
+            format!(
+                "🎛️🟢 Enabled player equaliser (Imported: **`{}`**){note}{reverting}",
+                self.file.filename
+            ),
+            ctx
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{Biquad, Curve, MAX_GAIN, MIN_GAIN};
+    use crate::bot::{
+        component::tuning::equaliser::{BAND_FREQUENCIES, DB_PER_GAIN},
+        error::component::tuning::equaliser::ParseCurveError,
+    };
+
+    const TOLERANCE: f64 = 0.01;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < TOLERANCE,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[rstest]
+    #[case("GraphicEQ: 20 -1; 1000 2; 20000 3", &[(20., -1.), (1_000., 2.), (20_000., 3.)])]
+    #[case("GraphicEQ: 1000 2; 20 -1;", &[(20., -1.), (1_000., 2.)])]
+    #[case("Preamp: -3 dB\nGraphicEQ: 100 0.5", &[(100., 0.5)])]
+    fn parse_graphic(#[case] input: &str, #[case] expected: &[(f64, f64)]) {
+        let Ok(Curve::Graphic(points)) = Curve::parse(input) else {
+            panic!("`{input}` should parse as a graphic curve");
+        };
+        assert_eq!(points, expected);
+    }
+
+    #[rstest]
+    #[case("GraphicEQ: 100")]
+    #[case("GraphicEQ: 100 1 2")]
+    #[case("GraphicEQ: 0 1")]
+    #[case("GraphicEQ: abc 1")]
+    fn parse_graphic_invalid_point(#[case] input: &str) {
+        assert!(matches!(
+            Curve::parse(input),
+            Err(ParseCurveError::GraphicPoint(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("Preamp: -6.2 dB")]
+    #[case("GraphicEQ: ;")]
+    #[case("Filter 1: OFF PK Fc 100 Hz Gain 3 dB Q 1")]
+    fn parse_unrecognised(#[case] input: &str) {
+        assert!(matches!(
+            Curve::parse(input),
+            Err(ParseCurveError::Unrecognised)
+        ));
+    }
+
+    #[rstest]
+    #[case("Filter 1: ON PK Fc 1000 Hz Gain 6 dB Q 1.41", 1)]
+    #[case("Preamp: -6 dB\nFilter 1: ON LSC Fc 105 Hz Gain 4 dB Q 0.7\nFilter 2: ON HSC Fc 10000 Hz Gain -2 dB", 2)]
+    #[case(
+        "Filter 1: ON PK Fc 100 Hz Gain 1 dB Q 1\nFilter 2: OFF PK Fc 200 Hz Gain 1 dB Q 1",
+        1
+    )]
+    fn parse_parametric(#[case] input: &str, #[case] expected: usize) {
+        let Ok(Curve::Parametric(filters)) = Curve::parse(input) else {
+            panic!("`{input}` should parse as a parametric curve");
+        };
+        assert_eq!(filters.len(), expected);
+    }
+
+    #[rstest]
+    #[case(" 1: OFF PK Fc 100 Hz Gain 1 dB Q 1")]
+    #[case(" 1: OFF garbage")]
+    fn parse_filter_off(#[case] input: &str) {
+        assert!(matches!(Curve::parse_filter(input), Ok(None)));
+    }
+
+    #[rstest]
+    #[case(" 1 ON PK Fc 100 Hz Gain 1 dB Q 1")]
+    #[case(" 1: MAYBE PK Fc 100 Hz Gain 1 dB Q 1")]
+    #[case(" 1: ON")]
+    #[case(" 1: ON PK Gain 1 dB Q 1")]
+    #[case(" 1: ON PK Fc 100 Hz Q 1")]
+    #[case(" 1: ON PK Fc abc Hz Gain 1 dB Q 1")]
+    #[case(" 1: ON PK Fc 0 Hz Gain 1 dB Q 1")]
+    #[case(" 1: ON PK Fc 24000 Hz Gain 1 dB Q 1")]
+    #[case(" 1: ON PK Fc 100 Hz Gain 1 dB Q 0")]
+    fn parse_filter_invalid(#[case] input: &str) {
+        assert!(matches!(
+            Curve::parse_filter(input),
+            Err(ParseCurveError::Filter(_))
+        ));
+    }
+
+    #[rstest]
+    #[case(" 1: ON LP Fc 100 Hz Gain 0 dB")]
+    #[case(" 1: ON NO Fc 100 Hz Gain 0 dB")]
+    fn parse_filter_unsupported(#[case] input: &str) {
+        assert!(matches!(
+            Curve::parse_filter(input),
+            Err(ParseCurveError::UnsupportedFilter(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("PK", 1_000., 6., 1_000., 6.)]
+    #[case("PK", 1_000., -6., 1_000., -6.)]
+    #[case("PK", 1_000., 6., 20., 0.)]
+    #[case("LSC", 100., 4., 20., 4.)]
+    #[case("LSC", 100., 4., 10_000., 0.)]
+    #[case("HSC", 8_000., -3., 20_000., -3.)]
+    #[case("HSC", 8_000., -3., 50., 0.)]
+    fn biquad_response(
+        #[case] kind: &str,
+        #[case] frequency: f64,
+        #[case] gain: f64,
+        #[case] at: f64,
+        #[case] expected: f64,
+    ) {
+        let biquad = Biquad::new(kind, frequency, gain, 1.).expect("filter kind is supported");
+        assert!((biquad.response_at(at) - expected).abs() < 0.1);
+    }
+
+    #[rstest]
+    #[case(10., 1.)]
+    #[case(100., 1.)]
+    #[case(1_000., 3.)]
+    #[case(10_000., 5.)]
+    #[case(20_000., 5.)]
+    fn graphic_gain_at(#[case] frequency: f64, #[case] expected: f64) {
+        let curve = Curve::Graphic(vec![(100., 1.), (10_000., 5.)]);
+        assert_close(curve.gain_at(frequency), expected);
+    }
+
+    #[test]
+    fn band_gains_from_flat_curve() {
+        let (gains, clamped) = Curve::Graphic(vec![(1_000., 0.)]).band_gains();
+        assert!(gains
+            .iter()
+            .all(|g| g.is_some_and(|g| g.abs() < f64::EPSILON)));
+        assert!(!clamped);
+    }
+
+    #[rstest]
+    #[case(8., 0.5, false)]
+    #[case(-4., -0.25, false)]
+    #[case(24., MAX_GAIN, true)]
+    #[case(-12., MIN_GAIN, true)]
+    fn band_gains_from_graphic_curve(
+        #[case] db: f64,
+        #[case] expected: f64,
+        #[case] expected_clamped: bool,
+    ) {
+        let (gains, clamped) = Curve::Graphic(vec![(1_000., db)]).band_gains();
+        assert!(gains
+            .iter()
+            .all(|g| g.is_some_and(|g| (g - expected).abs() < TOLERANCE)));
+        assert_eq!(clamped, expected_clamped);
+    }
+
+    #[test]
+    fn band_gains_from_parametric_curve() {
+        let peak = Biquad::new("PK", 1_000., 8., 2.).expect("filter kind is supported");
+        let (gains, clamped) = Curve::Parametric(vec![peak]).band_gains();
+
+        let peak_band = BAND_FREQUENCIES
+            .iter()
+            .position(|&f| f == 1_000)
+            .expect("1kHz is a band");
+        assert_close(
+            gains[peak_band].expect("every band is set"),
+            8. / DB_PER_GAIN,
+        );
+        assert_close(gains[0].expect("every band is set"), 0.);
+        assert!(!clamped);
+    }
+}
@@ -2,19 +2,25 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::bot::{
     command::macros::out,
-    component::tuning::{common_checks, set_filter},
+    component::tuning::{common_checks, schedule_revert, set_filter, snapshot_for_revert},
 };
 
 /// Disable the player equaliser
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "off")]
-pub struct Off;
+pub struct Off {
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
 
 impl crate::bot::command::model::BotSlashCommand for Off {
     async fn run(self, mut ctx: crate::bot::command::SlashCtx) -> crate::bot::error::CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         set_filter(&ctx, None::<super::SetEqualiser>).await?;
-        out!("🎛️🔴 Disabled equaliser", ctx);
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("🎛️🔴 Disabled equaliser{reverting}"), ctx);
     }
 }
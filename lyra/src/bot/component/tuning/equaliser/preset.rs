@@ -1,43 +1,146 @@
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use sqlx::{Pool, Postgres};
+use twilight_interactions::command::{AutocompleteValue, CommandModel, CreateCommand};
+use twilight_model::{
+    application::command::{CommandOptionChoice, CommandOptionChoiceValue},
+    id::{marker::GuildMarker, Id},
+};
 
-use crate::bot::{command::macros::out, component::tuning::equaliser::SetEqualiser};
+use crate::bot::{
+    command::{
+        macros::{out, what},
+        model::{BotAutocomplete, Ctx, CtxKind},
+        AutocompleteCtx,
+    },
+    component::tuning::equaliser::{SetEqualiser, EQUALISER_N},
+    core::r#const::discord::COMMAND_CHOICES_LIMIT,
+    error::command::AutocompleteResult,
+    gateway::ExpectedGuildIdAware,
+};
 
 lyra_proc::read_equaliser_presets_as!(EqualiserPreset);
 
-impl From<EqualiserPreset> for SetEqualiser {
-    fn from(value: EqualiserPreset) -> Self {
-        let gains = value.gains();
-        Self(core::array::from_fn(|i| {
-            lavalink_rs::model::player::Equalizer {
-                band: i as u8,
-                gain: gains[i],
-            }
-        }))
+pub(super) fn is_built_in(name: &str) -> bool {
+    EqualiserPreset::from_name(name).is_some()
+}
+
+/// Looks up the gains of a built-in preset, or of one saved in the guild
+pub(in crate::bot::component) async fn resolve_gains(
+    db: &Pool<Postgres>,
+    guild_id: Id<GuildMarker>,
+    name: &str,
+) -> Result<Option<[f64; EQUALISER_N]>, sqlx::Error> {
+    if let Some(preset) = EqualiserPreset::from_name(name) {
+        return Ok(Some(preset.gains()));
+    }
+
+    let saved = sqlx::query!(
+        r"--sql
+        SELECT gains FROM equaliser_presets WHERE guild = $1 AND name = $2;
+        ",
+        guild_id.get() as i64,
+        name,
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(saved.map(|s| {
+        <[f64; EQUALISER_N]>::try_from(s.gains)
+            .expect("saved presets must have exactly `EQUALISER_N` bands")
+    }))
+}
+
+pub(super) async fn saved_preset_names(
+    ctx: &Ctx<impl CtxKind>,
+) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query!(
+        r"--sql
+        SELECT name FROM equaliser_presets WHERE guild = $1 ORDER BY name;
+        ",
+        ctx.guild_id().get() as i64,
+    )
+    .fetch_all(ctx.db())
+    .await
+    .map(|v| v.into_iter().map(|r| r.name).collect())
+}
+
+pub(super) fn generate_saved_preset_choice(name: String) -> CommandOptionChoice {
+    CommandOptionChoice {
+        name: format!("💾 {name}"),
+        name_localizations: None,
+        value: CommandOptionChoiceValue::String(name),
     }
 }
 
+pub(super) fn matches_preset_name(name: &str, focused: &str) -> bool {
+    name.to_lowercase().contains(&focused.to_lowercase())
+}
+
 /// Enable the player equaliser from presets
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "preset")]
 pub struct Preset {
     /// Which preset to use?
-    preset: EqualiserPreset,
+    #[command(autocomplete = true)]
+    preset: String,
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
 }
 
 impl crate::bot::command::model::BotSlashCommand for Preset {
     async fn run(self, mut ctx: crate::bot::command::SlashCtx) -> crate::bot::error::CommandResult {
-        super::super::common_checks(&ctx)?;
+        super::super::common_checks(&ctx).await?;
+        let revert = super::super::snapshot_for_revert(&ctx, self.duration).await?;
 
-        let preset_name = self.preset.value();
-        let update = Some(SetEqualiser::from(self.preset));
+        let Some(gains) = resolve_gains(ctx.db(), ctx.guild_id(), &self.preset).await? else {
+            what!(
+                format!("No equaliser preset named **`{}`** exists.", self.preset),
+                ctx
+            );
+        };
 
-        super::super::set_filter(&ctx, update).await?;
+        let update = SetEqualiser::from(gains);
+        let Some(mut ctx) = super::super::set_filter_guarded(ctx, Some(update)).await? else {
+            return Ok(());
+        };
+        let reverting = super::super::schedule_revert(revert).await?;
         out!(
             format!(
-                "🎛️🟢 Enabled player equaliser (Preset: **`{}`**)",
-                preset_name
+                "🎛️🟢 Enabled player equaliser (Preset: **`{}`**){reverting}",
+                self.preset
             ),
             ctx
         );
     }
 }
+
+#[derive(CommandModel)]
+#[command(autocomplete = true)]
+pub struct Autocomplete {
+    preset: AutocompleteValue<String>,
+}
+
+impl BotAutocomplete for Autocomplete {
+    async fn execute(self, mut ctx: AutocompleteCtx) -> AutocompleteResult {
+        let AutocompleteValue::Focused(focused) = self.preset else {
+            unreachable!("exactly one option is focused");
+        };
+
+        let built_in = EqualiserPreset::NAMES
+            .into_iter()
+            .filter(|n| matches_preset_name(n, &focused))
+            .map(|n| CommandOptionChoice {
+                name: n.to_owned(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::String(n.to_owned()),
+            });
+        let saved = saved_preset_names(&ctx)
+            .await?
+            .into_iter()
+            .filter(|n| matches_preset_name(n, &focused))
+            .map(generate_saved_preset_choice);
+
+        let choices = built_in.chain(saved).take(COMMAND_CHOICES_LIMIT).collect();
+        Ok(ctx.autocomplete(choices).await?)
+    }
+}
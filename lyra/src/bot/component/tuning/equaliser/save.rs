@@ -0,0 +1,81 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::macros::{bad, out},
+    component::tuning::{common_checks, equaliser::gains_from_bands},
+    core::r#const::misc::SAVED_EQUALISER_PRESETS_LIMIT,
+    gateway::ExpectedGuildIdAware,
+    lavalink::ExpectedPlayerAware,
+};
+
+/// Save the current player equaliser as a preset for this server
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "save")]
+pub struct Save {
+    /// What should the preset be named?
+    #[command(min_length = 1, max_length = 32)]
+    name: String,
+}
+
+impl crate::bot::command::model::BotSlashCommand for Save {
+    async fn run(self, mut ctx: crate::bot::command::SlashCtx) -> crate::bot::error::CommandResult {
+        common_checks(&ctx).await?;
+
+        let name = self.name.trim();
+        if super::preset::is_built_in(name) {
+            bad!(
+                format!("**`{name}`** is already the name of a built-in preset."),
+                ctx
+            );
+        }
+
+        let Some(bands) = ctx
+            .player()
+            .get_player()
+            .await?
+            .filters
+            .and_then(|f| f.equalizer)
+        else {
+            bad!("No equaliser is currently enabled.", ctx);
+        };
+        let gains = gains_from_bands(&bands);
+
+        let others = sqlx::query!(
+            r#"--sql
+            SELECT COUNT(*) AS "count!" FROM equaliser_presets WHERE guild = $1 AND name <> $2;
+            "#,
+            ctx.guild_id().get() as i64,
+            name,
+        )
+        .fetch_one(ctx.db())
+        .await?
+        .count;
+        if others >= SAVED_EQUALISER_PRESETS_LIMIT {
+            bad!(
+                format!(
+                    "This server already has `{SAVED_EQUALISER_PRESETS_LIMIT}` saved equaliser presets. Delete one first."
+                ),
+                ctx
+            );
+        }
+
+        sqlx::query!(
+            r"--sql
+            INSERT INTO equaliser_presets
+                (guild, name, gains)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild, name) DO UPDATE SET gains = $3;
+            ",
+            ctx.guild_id().get() as i64,
+            name,
+            &gains as &[f64],
+        )
+        .execute(ctx.db())
+        .await?;
+
+        out!(
+            format!("🎛️💾 Saved the current equaliser as **`{name}`**"),
+            ctx
+        );
+    }
+}
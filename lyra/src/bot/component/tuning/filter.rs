@@ -1,8 +1,10 @@
 mod all_off;
 mod channel_mix;
 mod distortion;
+mod karaoke;
 mod low_pass;
 mod pitch;
+mod preset;
 mod rotation;
 mod tremolo;
 mod vibrato;
@@ -11,6 +13,7 @@ use std::marker::PhantomData;
 
 use lavalink_rs::model::player::{Filters, TremoloVibrato};
 use lyra_proc::BotCommandGroup;
+pub(in crate::bot::component) use preset::FilterPreset;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use super::UpdateFilter;
@@ -125,8 +128,12 @@ pub enum Filter {
     ChannelMix(channel_mix::ChannelMix),
     #[command(name = "low-pass")]
     LowPass(low_pass::LowPass),
+    #[command(name = "karaoke")]
+    Karaoke(karaoke::Karaoke),
     #[command(name = "pitch")]
     Pitch(pitch::Pitch),
+    #[command(name = "preset")]
+    Preset(preset::Preset),
     #[command(name = "all-off")]
     AllOff(all_off::AllOff),
 }
@@ -30,18 +30,28 @@ impl super::UpdateFilter for ResetAllExceptSpeed {
 /// Disable all filter
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "all-off")]
-pub struct AllOff;
+pub struct AllOff {
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
 
 impl crate::bot::command::model::BotSlashCommand for AllOff {
     async fn run(
         self,
         mut ctx: crate::bot::command::SlashCtx,
     ) -> crate::bot::error::command::Result {
-        super::super::common_checks(&ctx)?;
+        super::super::common_checks(&ctx).await?;
+        let revert = super::super::snapshot_for_revert(&ctx, self.duration).await?;
 
         super::super::set_filter(&ctx, ResetAllExceptSpeed).await?;
-        ctx.player_data().write().await.pitch_mut().reset();
-
-        out!("🪄🔴 Disabled all filters", ctx);
+        let data = ctx.player_data();
+        let mut data_w = data.write().await;
+        data_w.pitch_mut().reset();
+        data_w.take_filter_preset();
+        drop(data_w);
+
+        let reverting = super::super::schedule_revert(revert).await?;
+        out!(format!("🪄🔴 Disabled all filters{reverting}"), ctx);
     }
 }
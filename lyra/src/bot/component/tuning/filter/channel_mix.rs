@@ -8,7 +8,7 @@ use crate::bot::{
         model::BotSlashCommand,
         SlashCtx,
     },
-    component::tuning::{common_checks, set_filter},
+    component::tuning::{common_checks, schedule_revert, set_filter, snapshot_for_revert},
     error::CommandResult,
 };
 
@@ -80,11 +80,15 @@ pub struct On {
     /// Keep the right channel by how much? [0~1] (If not given, leave this setting unchanged)
     #[command(min_value = 0, max_value = 1)]
     right_to_right: Option<f64>,
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
 }
 
 impl BotSlashCommand for On {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         let Some(update) = SetChannelMix::new(
             self.left_to_left,
@@ -103,20 +107,27 @@ impl BotSlashCommand for On {
         };
 
         set_filter(&ctx, Some(update)).await?;
-        out!("⚗️🟢 Enabled channel mix)", ctx);
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("⚗️🟢 Enabled channel mix){reverting}"), ctx);
     }
 }
 
 /// Disable Channel Mix
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "off")]
-pub struct Off;
+pub struct Off {
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
 
 impl BotSlashCommand for Off {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         set_filter(&ctx, None::<SetChannelMix>).await?;
-        out!("⚗️🔴 Disabled channel mix", ctx);
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("⚗️🔴 Disabled channel mix{reverting}"), ctx);
     }
 }
@@ -8,7 +8,9 @@ use crate::bot::{
         model::BotSlashCommand,
         SlashCtx,
     },
-    component::tuning::{common_checks, set_filter},
+    component::tuning::{
+        common_checks, schedule_revert, set_filter, set_filter_guarded, snapshot_for_revert,
+    },
     error::CommandResult,
 };
 
@@ -81,11 +83,15 @@ pub struct On {
     offset: Option<f64>,
     /// What scale? (If not given, leave this setting unchanged)
     scale: Option<f64>,
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
 }
 
 impl BotSlashCommand for On {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         let distortion = LavalinkDistortion {
             sin_offset: self.sin_offset,
@@ -108,21 +114,30 @@ impl BotSlashCommand for On {
             );
         };
 
-        set_filter(&ctx, Some(update)).await?;
-        out!(format!("🍭🟢 Enabled distortion"), ctx);
+        let Some(mut ctx) = set_filter_guarded(ctx, Some(update)).await? else {
+            return Ok(());
+        };
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("🍭🟢 Enabled distortion{reverting}"), ctx);
     }
 }
 
 /// Disable Distortion
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "off")]
-pub struct Off;
+pub struct Off {
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
 
 impl BotSlashCommand for Off {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         set_filter(&ctx, None::<SetDistortion>).await?;
-        out!("🍭🔴 Disabled distortion", ctx);
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("🍭🔴 Disabled distortion{reverting}"), ctx);
     }
 }
@@ -0,0 +1,157 @@
+use lavalink_rs::model::player::{Filters, Karaoke as LavalinkKaraoke};
+use lyra_proc::BotCommandGroup;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::{
+        macros::{bad, out},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    component::tuning::{common_checks, schedule_revert, set_filter, snapshot_for_revert},
+    error::CommandResult,
+};
+
+struct SetKaraoke(LavalinkKaraoke);
+
+impl SetKaraoke {
+    const ERR_MARGIN: f64 = f64::EPSILON;
+    const SANE_DEFAULT_LEVEL: f64 = 1.;
+    const SANE_DEFAULT_MONO_LEVEL: f64 = 1.;
+    const SANE_DEFAULT_FILTER_BAND: f64 = 220.;
+    const SANE_DEFAULT_FILTER_WIDTH: f64 = 100.;
+
+    fn new(
+        level: Option<f64>,
+        mono_level: Option<f64>,
+        filter_band: Option<f64>,
+        filter_width: Option<f64>,
+    ) -> Option<Self> {
+        ((level, mono_level) != (Some(0.), Some(0.))).then_some(Self(LavalinkKaraoke {
+            level,
+            mono_level,
+            filter_band,
+            filter_width,
+        }))
+    }
+
+    fn settings(&self) -> KaraokeSettings {
+        let non_default = |value: Option<f64>, default: f64| {
+            value.filter(|v| (v - default).abs() > Self::ERR_MARGIN)
+        };
+
+        KaraokeSettings {
+            level: non_default(self.0.level, Self::SANE_DEFAULT_LEVEL),
+            mono_level: non_default(self.0.mono_level, Self::SANE_DEFAULT_MONO_LEVEL),
+            filter_band: non_default(self.0.filter_band, Self::SANE_DEFAULT_FILTER_BAND),
+            filter_width: non_default(self.0.filter_width, Self::SANE_DEFAULT_FILTER_WIDTH),
+        }
+    }
+}
+
+struct KaraokeSettings {
+    level: Option<f64>,
+    mono_level: Option<f64>,
+    filter_band: Option<f64>,
+    filter_width: Option<f64>,
+}
+
+impl std::fmt::Display for KaraokeSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let settings = [
+            self.level.map(|l| format!("Level: `{l}`")),
+            self.mono_level.map(|m| format!("Mono Level: `{m}`")),
+            self.filter_band.map(|b| format!("Filter Band: `{b} Hz.`")),
+            self.filter_width
+                .map(|w| format!("Filter Width: `{w} Hz.`")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if settings.is_empty() {
+            return write!(f, "**`Default Settings`**");
+        }
+        write!(f, "{}", settings.join(", "))
+    }
+}
+
+impl crate::bot::component::tuning::UpdateFilter for Option<SetKaraoke> {
+    fn apply(self, filter: Filters) -> Filters {
+        Filters {
+            karaoke: self.map(|k| k.0),
+            ..filter
+        }
+    }
+}
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "karaoke", desc = ".")]
+pub enum Karaoke {
+    #[command(name = "on")]
+    On(On),
+    #[command(name = "off")]
+    Off(Off),
+}
+
+/// Enable Karaoke: Cancels out the vocals of a frequency band, centered in the stereo channels.
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "on")]
+pub struct On {
+    /// Cancel out the vocals by how much? [0~1] (If not given, a reasonable default is used)
+    #[command(min_value = 0, max_value = 1)]
+    level: Option<f64>, // default: 1.0 [https://github.com/lavalink-devs/Lavalink/blob/master/protocol/src/commonMain/kotlin/dev/arbjerg/lavalink/protocol/v4/filters.kt]
+    /// Cancel out the mono channel by how much? [0~1] (If not given, a reasonable default is used)
+    #[command(min_value = 0, max_value = 1)]
+    mono_level: Option<f64>, // default: 1.0 [https://github.com/lavalink-devs/Lavalink/blob/master/protocol/src/commonMain/kotlin/dev/arbjerg/lavalink/protocol/v4/filters.kt]
+    /// Cancel out the vocals centered around what frequency? [in Hz.] (If not given, a reasonable default is used)
+    #[command(min_value = 0)]
+    filter_band: Option<f64>, // default: 220.0 [https://github.com/lavalink-devs/Lavalink/blob/master/protocol/src/commonMain/kotlin/dev/arbjerg/lavalink/protocol/v4/filters.kt]
+    /// Cancel out the vocals within how wide of a frequency band? [in Hz.] (If not given, a reasonable default is used)
+    #[command(min_value = 0)]
+    filter_width: Option<f64>, // default: 100.0 [https://github.com/lavalink-devs/Lavalink/blob/master/protocol/src/commonMain/kotlin/dev/arbjerg/lavalink/protocol/v4/filters.kt]
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
+
+impl BotSlashCommand for On {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
+
+        let Some(update) = SetKaraoke::new(
+            self.level,
+            self.mono_level,
+            self.filter_band,
+            self.filter_width,
+        ) else {
+            bad!("Both level and mono level must not be zero.", ctx);
+        };
+        let settings = update.settings();
+
+        set_filter(&ctx, Some(update)).await?;
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("🎤🟢 Enabled karaoke ({settings}){reverting}"), ctx);
+    }
+}
+
+/// Disable Karaoke
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "off")]
+pub struct Off {
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
+
+impl BotSlashCommand for Off {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
+
+        set_filter(&ctx, None::<SetKaraoke>).await?;
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("🎤🔴 Disabled karaoke{reverting}"), ctx);
+    }
+}
@@ -8,7 +8,7 @@ use crate::bot::{
         model::BotSlashCommand,
         SlashCtx,
     },
-    component::tuning::{common_checks, set_filter},
+    component::tuning::{common_checks, schedule_revert, set_filter, snapshot_for_revert},
     error::CommandResult,
 };
 
@@ -75,11 +75,15 @@ pub struct On {
     /// How much intensity for the low pass smoothing? (If not given, a reasonable default is used)
     #[command(min_value = 1)]
     smoothing: Option<f64>, // default: 20 [https://github.com/lavalink-devs/Lavalink/blob/master/protocol/src/commonMain/kotlin/dev/arbjerg/lavalink/protocol/v4/filters.kt#L120]
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
 }
 
 impl BotSlashCommand for On {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         let Some(update) = SetLowPass::new(self.smoothing) else {
             bad!(
@@ -90,20 +94,30 @@ impl BotSlashCommand for On {
         let settings = update.settings();
 
         set_filter(&ctx, Some(update)).await?;
-        out!(format!("😶‍🌫️🟢 Enabled low pass ({settings})"), ctx);
+        let reverting = schedule_revert(revert).await?;
+        out!(
+            format!("😶‍🌫️🟢 Enabled low pass ({settings}){reverting}"),
+            ctx
+        );
     }
 }
 
 /// Disable Low Pass
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "off")]
-pub struct Off;
+pub struct Off {
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
 
 impl BotSlashCommand for Off {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         set_filter(&ctx, None::<SetLowPass>).await?;
-        out!("😶‍🌫️🔴 Disabled low pass", ctx);
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("😶‍🌫️🔴 Disabled low pass{reverting}"), ctx);
     }
 }
@@ -12,18 +12,21 @@ use lyra_proc::BotCommandGroup;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::bot::{
+    command::model::{Ctx, CtxKind},
+    component::tuning::{check_bounds, BoundedTuning},
+    error::CommandResult,
     gateway::ExpectedGuildIdAware,
-    lavalink::{DelegateMethods, LavalinkAware, Pitch as PitchModel},
+    lavalink::{DelegateMethods, ExpectedPlayerDataAware, LavalinkAware, Pitch as PitchModel},
 };
 
-enum Tier {
+pub(in crate::bot::component::tuning) enum Tier {
     Default,
     High,
     Low,
 }
 
 impl Tier {
-    const fn emoji(&self) -> &'static str {
+    pub(in crate::bot::component::tuning) const fn emoji(&self) -> &'static str {
         match self {
             Self::Default => "🧑",
             Self::Low => "🐋",
@@ -33,7 +36,7 @@ impl Tier {
 }
 
 impl PitchModel {
-    fn tier(&self) -> Tier {
+    pub(in crate::bot::component::tuning) fn tier(&self) -> Tier {
         match self.checked_get() {
             None => Tier::Default,
             Some(0.0..=1.0) => Tier::Low,
@@ -42,6 +45,16 @@ impl PitchModel {
     }
 }
 
+/// Checks the pitch that shifting by `half_tones` would result in against the guild's bounds
+async fn check_shifted_pitch_bounds(
+    ctx: &Ctx<impl CtxKind>,
+    half_tones: NonZeroI64,
+) -> CommandResult {
+    let mut shifted = ctx.player_data().read().await.pitch().clone();
+    shifted.shift(half_tones);
+    check_bounds(ctx, BoundedTuning::Pitch, shifted.get()).await
+}
+
 async fn shift_pitch(
     ctx: &(impl LavalinkAware + ExpectedGuildIdAware + Sync),
     half_tones: NonZeroI64,
@@ -4,7 +4,11 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::bot::{
     command::{macros::out, model::BotSlashCommand, SlashCtx},
-    component::tuning::{common_checks, filter::pitch::shift_pitch},
+    component::tuning::{
+        common_checks,
+        filter::pitch::{check_shifted_pitch_bounds, shift_pitch},
+        schedule_revert, snapshot_for_revert,
+    },
     error::CommandResult,
 };
 
@@ -15,17 +19,26 @@ pub struct Down {
     /// How many half tones? (If not given, 2)
     #[command(min_value = 1)]
     half_tones: Option<i64>,
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
 }
 
 impl BotSlashCommand for Down {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         let half_tones =
             NonZeroI64::new(self.half_tones.unwrap_or(2)).expect("self.half_tones is non-zero");
+        check_shifted_pitch_bounds(&ctx, -half_tones).await?;
         let (old, new) = shift_pitch(&ctx, -half_tones).await?;
 
         let emoji = new.tier().emoji();
-        out!(format!("{emoji}**`ー`** ~~`{old}`~~ ➜ **`{new}`**"), ctx);
+        let reverting = schedule_revert(revert).await?;
+        out!(
+            format!("{emoji}**`ー`** ~~`{old}`~~ ➜ **`{new}`**{reverting}"),
+            ctx
+        );
     }
 }
@@ -7,7 +7,10 @@ use crate::bot::{
         model::BotSlashCommand,
         SlashCtx,
     },
-    component::tuning::{common_checks, set_filter},
+    component::tuning::{
+        check_bounds, common_checks, schedule_revert, set_filter, snapshot_for_revert,
+        BoundedTuning,
+    },
     error::CommandResult,
     lavalink::ExpectedPlayerDataAware,
 };
@@ -66,23 +69,29 @@ pub struct Set {
     /// Set the playback pitch with what multiplier? [Default: 1.0]
     #[command(min_value = 0)]
     multiplier: f64,
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
 }
 
 impl BotSlashCommand for Set {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         let Some(update) = SetPitch::new(self.multiplier) else {
             bad!("Multiplier must not be 0", ctx);
         };
 
         let multiplier = update.multiplier();
+        check_bounds(&ctx, BoundedTuning::Pitch, multiplier).await?;
         let emoji = update.tier().emoji();
         set_filter(&ctx, update).await?;
         ctx.player_data().write().await.pitch_mut().set(multiplier);
 
+        let reverting = schedule_revert(revert).await?;
         out!(
-            format!("{emoji} Set the playback pitch to `{multiplier}`×."),
+            format!("{emoji} Set the playback pitch to `{multiplier}`×{reverting}."),
             ctx
         );
     }
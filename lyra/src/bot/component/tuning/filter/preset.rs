@@ -0,0 +1,131 @@
+use lyra_proc::BotCommandGroup;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::bot::{
+    command::{
+        macros::{bad, out},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    component::tuning::{
+        check_timescale_bounds, common_checks, guard_loudness, schedule_revert, snapshot_for_revert,
+    },
+    error::CommandResult,
+    lavalink::{AppliedFilterPreset, ExpectedPlayerAware, ExpectedPlayerDataAware},
+};
+
+lyra_proc::read_filter_presets_as!(FilterPreset);
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "preset", desc = ".")]
+pub enum Preset {
+    #[command(name = "on")]
+    On(On),
+    #[command(name = "off")]
+    Off(Off),
+}
+
+/// Enable a filter preset: Changes several filters at once.
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "on")]
+pub struct On {
+    /// Which preset to use?
+    preset: FilterPreset,
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
+
+impl BotSlashCommand for On {
+    async fn run(self, ctx: SlashCtx) -> CommandResult {
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
+
+        let player = ctx.player();
+        let data = ctx.player_data();
+        let mut filters = player.get_player().await?.filters.unwrap_or_default();
+        let data_r = data.read().await;
+        let (old_preset, mut pitch, volume) = (
+            data_r.filter_preset().cloned(),
+            data_r.pitch().clone(),
+            data_r.volume(),
+        );
+        drop(data_r);
+
+        if let Some(old_preset) = old_preset {
+            let (reverted, old_pitch) = old_preset.revert(filters);
+            filters = reverted;
+            if let Some(old_pitch) = old_pitch {
+                pitch = old_pitch;
+            }
+        }
+
+        let preset_name = self.preset.value();
+        let applied = self.preset.filters();
+        let changes_timescale = applied.timescale.is_some();
+        let preset = AppliedFilterPreset::new(preset_name, applied, filters.clone(), pitch.clone());
+        if let Some(preset_pitch) = preset.pitch() {
+            pitch.set(preset_pitch);
+        }
+
+        let filters = preset.apply_to(filters);
+        if let Some(timescale) = filters.timescale.as_ref().filter(|_| changes_timescale) {
+            check_timescale_bounds(&ctx, timescale).await?;
+        }
+
+        let Some(mut ctx) = guard_loudness(ctx, volume, &filters).await? else {
+            return Ok(());
+        };
+        player.set_filters(filters).await?;
+        let mut data_w = data.write().await;
+        *data_w.pitch_mut() = pitch;
+        data_w.set_filter_preset(preset);
+        drop(data_w);
+
+        let reverting = schedule_revert(revert).await?;
+        out!(
+            format!("🪄🟢 Enabled filter preset **`{preset_name}`**{reverting}"),
+            ctx
+        );
+    }
+}
+
+/// Disable the filter preset, reverting only what it changed
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "off")]
+pub struct Off {
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
+
+impl BotSlashCommand for Off {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
+
+        let data = ctx.player_data();
+        let mut data_w = data.write().await;
+        let Some(preset) = data_w.take_filter_preset() else {
+            drop(data_w);
+            bad!("No filter preset is currently enabled.", ctx);
+        };
+        let preset_name = preset.name();
+
+        let player = ctx.player();
+        let filters = player.get_player().await?.filters.unwrap_or_default();
+        let (filters, pitch) = preset.revert(filters);
+        if let Some(pitch) = pitch {
+            *data_w.pitch_mut() = pitch;
+        }
+
+        player.set_filters(filters).await?;
+        drop(data_w);
+
+        let reverting = schedule_revert(revert).await?;
+        out!(
+            format!("🪄🔴 Disabled filter preset **`{preset_name}`**{reverting}"),
+            ctx
+        );
+    }
+}
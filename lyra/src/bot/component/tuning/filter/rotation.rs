@@ -8,7 +8,7 @@ use crate::bot::{
         model::BotSlashCommand,
         SlashCtx,
     },
-    component::tuning::{common_checks, set_filter},
+    component::tuning::{common_checks, schedule_revert, set_filter, snapshot_for_revert},
     error::CommandResult,
 };
 
@@ -50,11 +50,15 @@ pub enum Rotation {
 pub struct On {
     /// Rotate at what frequency? [in Hz.]
     frequency: f64,
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
 }
 
 impl BotSlashCommand for On {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         let Some(update) = SetRotation::new(self.frequency) else {
             bad!("Frequency must not be zero.", ctx);
@@ -62,8 +66,9 @@ impl BotSlashCommand for On {
         let frequency = update.frequency();
 
         set_filter(&ctx, Some(update)).await?;
+        let reverting = schedule_revert(revert).await?;
         out!(
-            format!("🍳🟢 Enabled rotation (Frequency: `{frequency} Hz.`)"),
+            format!("🍳🟢 Enabled rotation (Frequency: `{frequency} Hz.`){reverting}"),
             ctx
         );
     }
@@ -72,13 +77,19 @@ impl BotSlashCommand for On {
 /// Disable Rotation
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "off")]
-pub struct Off;
+pub struct Off {
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
 
 impl BotSlashCommand for Off {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         set_filter(&ctx, None::<SetRotation>).await?;
-        out!("🍳🔴 Disabled rotation", ctx);
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("🍳🔴 Disabled rotation{reverting}"), ctx);
     }
 }
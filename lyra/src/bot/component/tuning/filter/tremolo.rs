@@ -7,7 +7,9 @@ use crate::bot::{
         model::BotSlashCommand,
         SlashCtx,
     },
-    component::tuning::{common_checks, filter::SetTremolo, set_filter},
+    component::tuning::{
+        common_checks, filter::SetTremolo, schedule_revert, set_filter, snapshot_for_revert,
+    },
     error::CommandResult,
 };
 
@@ -30,11 +32,15 @@ pub struct On {
     /// Oscillate by how much intensity? [0~1, excluding 0] (If not given, a reasonable default is used)
     #[command(min_value = 0, max_value = 1)]
     depth: Option<f64>, // default: 0.5 [https://github.com/lavalink-devs/Lavalink/blob/master/protocol/src/commonMain/kotlin/dev/arbjerg/lavalink/protocol/v4/filters.kt#L83]
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
 }
 
 impl BotSlashCommand for On {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         let Some(update) = SetTremolo::new(self.frequency, self.depth) else {
             bad!("Both frequency and depth must not be zero.", ctx);
@@ -42,20 +48,27 @@ impl BotSlashCommand for On {
         let settings = update.settings();
 
         set_filter(&ctx, Some(update)).await?;
-        out!(format!("🎸🟢 Enabled tremolo ({settings})"), ctx);
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("🎸🟢 Enabled tremolo ({settings}){reverting}"), ctx);
     }
 }
 
 /// Disable Tremolo
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "off")]
-pub struct Off;
+pub struct Off {
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
+}
 
 impl BotSlashCommand for Off {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         set_filter(&ctx, None::<SetTremolo>).await?;
-        out!("🎸🔴 Disabled tremolo", ctx);
+        let reverting = schedule_revert(revert).await?;
+        out!(format!("🎸🔴 Disabled tremolo{reverting}"), ctx);
     }
 }
@@ -7,19 +7,29 @@ use crate::bot::{
         model::BotSlashCommand,
         SlashCtx,
     },
-    component::tuning::common_checks,
+    component::tuning::{
+        check_bounds, common_checks, schedule_revert, snapshot_for_revert, BoundedTuning,
+    },
     error::CommandResult,
 };
 
 use super::UpdateFilter;
-enum Tier {
+pub(super) enum Tier {
     Default,
     Fast,
     Slow,
 }
 
 impl Tier {
-    const fn emoji(&self) -> &'static str {
+    pub(super) fn from_multiplier(multiplier: Option<f64>) -> Self {
+        match multiplier {
+            None => Self::Default,
+            Some(0.0..=1.0) => Self::Slow,
+            _ => Self::Fast,
+        }
+    }
+
+    pub(super) const fn emoji(&self) -> &'static str {
         match self {
             Self::Default => "🚶",
             Self::Fast => "🐇",
@@ -70,11 +80,7 @@ impl SpeedFilter {
     }
 
     fn tier(&self) -> Tier {
-        match self.multiplier {
-            None => Tier::Default,
-            Some(0.0..=1.0) => Tier::Slow,
-            _ => Tier::Fast,
-        }
+        Tier::from_multiplier(self.multiplier)
     }
 }
 
@@ -98,11 +104,15 @@ pub struct Speed {
     multiplier: f64,
     /// Also shifts the playback pitch? (If not given, no)
     pitch_shift: Option<bool>,
+    /// Revert after how many seconds, or when the track ends? [1~3600s] (If not given, keep it)
+    #[command(min_value = 1, max_value = 3_600)]
+    duration: Option<i64>,
 }
 
 impl BotSlashCommand for Speed {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
+        let revert = snapshot_for_revert(&ctx, self.duration).await?;
 
         let Some(filter) = SpeedFilter::new(self.multiplier, self.pitch_shift.unwrap_or_default())
         else {
@@ -110,11 +120,13 @@ impl BotSlashCommand for Speed {
         };
 
         let multiplier = filter.multiplier();
+        check_bounds(&ctx, BoundedTuning::Speed, multiplier).await?;
         let emoji = filter.tier().emoji();
         super::set_filter(&ctx, filter).await?;
 
+        let reverting = schedule_revert(revert).await?;
         out!(
-            format!("{emoji} Set the playback speed to `{multiplier}`×."),
+            format!("{emoji} Set the playback speed to `{multiplier}`×{reverting}."),
             ctx
         );
     }
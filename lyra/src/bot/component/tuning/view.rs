@@ -0,0 +1,198 @@
+use std::num::NonZeroU16;
+
+use lavalink_rs::model::player::{Equalizer, Filters};
+use lyra_proc::BotCommandGroup;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use super::{
+    equaliser::{gains_from_bands, BAND_FREQUENCIES},
+    speed::Tier as SpeedTier,
+    volume,
+};
+use crate::bot::{
+    command::{check, model::BotSlashCommand, SlashCtx},
+    core::r#const::{colours::EMBED_DEFAULT, text::EMPTY_EMBED_FIELD},
+    error::CommandResult,
+    gateway::ExpectedGuildIdAware,
+    lavalink::{ExpectedPlayerAware, ExpectedPlayerDataAware, LavalinkAware},
+};
+
+/// Gain cells below and above zero, each cell being an eighth of the maximum gain
+const NEGATIVE_CELLS: usize = 2;
+const POSITIVE_CELLS: usize = 8;
+const GAIN_PER_CELL: f64 = 0.125;
+
+fn band_frequency(frequency: u16) -> String {
+    if frequency < 1_000 {
+        return frequency.to_string();
+    }
+    let kilo = f64::from(frequency) / 1_000.;
+    format!("{kilo}k")
+}
+
+fn equaliser_graph(bands: &[Equalizer]) -> String {
+    let gains = gains_from_bands(bands);
+    let lines = gains
+        .iter()
+        .zip(BAND_FREQUENCIES)
+        .map(|(&gain, frequency)| {
+            let cells = (gain.abs() / GAIN_PER_CELL).round() as usize;
+            let (negative, positive) = if gain < 0. {
+                (cells.min(NEGATIVE_CELLS), 0)
+            } else {
+                (0, cells.min(POSITIVE_CELLS))
+            };
+
+            format!(
+                "{:>4} {}{}┃{}{} {:+.3}",
+                band_frequency(frequency),
+                " ".repeat(NEGATIVE_CELLS - negative),
+                "█".repeat(negative),
+                "█".repeat(positive),
+                " ".repeat(POSITIVE_CELLS - positive),
+                gain
+            )
+        });
+
+    format!("```\n{}\n```", lines.collect::<Vec<_>>().join("\n"))
+}
+
+fn active_filters(filters: &Filters) -> Vec<String> {
+    let tremolo = filters.tremolo.as_ref().map(|t| {
+        format!(
+            "🎸 Tremolo: Frequency `{} Hz.`, Depth `{}`",
+            t.frequency.unwrap_or(2.),
+            t.depth.unwrap_or(0.5)
+        )
+    });
+    let vibrato = filters.vibrato.as_ref().map(|v| {
+        format!(
+            "🎻 Vibrato: Frequency `{} Hz.`, Depth `{}`",
+            v.frequency.unwrap_or(2.),
+            v.depth.unwrap_or(0.5)
+        )
+    });
+    let rotation = filters
+        .rotation
+        .as_ref()
+        .map(|r| format!("🎧 Rotation: `{} Hz.`", r.rotation_hz.unwrap_or_default()));
+    let distortion = filters
+        .distortion
+        .as_ref()
+        .map(|_| String::from("🎚️ Distortion: **`Custom Settings`**"));
+    let channel_mix = filters.channel_mix.as_ref().map(|c| {
+        format!(
+            "🔀 Channel Mix: L→L `{}`, L→R `{}`, R→L `{}`, R→R `{}`",
+            c.left_to_left.unwrap_or(1.),
+            c.left_to_right.unwrap_or_default(),
+            c.right_to_left.unwrap_or_default(),
+            c.right_to_right.unwrap_or(1.)
+        )
+    });
+    let low_pass = filters
+        .low_pass
+        .as_ref()
+        .map(|l| format!("😶‍🌫️ Low Pass: Smoothing `{:.1}`", l.smoothing.unwrap_or(20.)));
+    let karaoke = filters.karaoke.as_ref().map(|k| {
+        format!(
+            "🎤 Karaoke: Level `{}`, Mono Level `{}`, Filter Band `{} Hz.`, Filter Width `{} Hz.`",
+            k.level.unwrap_or(1.),
+            k.mono_level.unwrap_or(1.),
+            k.filter_band.unwrap_or(220.),
+            k.filter_width.unwrap_or(100.)
+        )
+    });
+
+    [
+        tremolo,
+        vibrato,
+        rotation,
+        distortion,
+        channel_mix,
+        low_pass,
+        karaoke,
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[derive(CommandModel, CreateCommand, BotCommandGroup)]
+#[command(name = "tuning", desc = ".", dm_permission = false)]
+pub enum Tuning {
+    #[command(name = "view")]
+    View(View),
+}
+
+/// Views the currently applied volume, speed, pitch, filters and equaliser
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "view")]
+pub struct View;
+
+impl BotSlashCommand for View {
+    async fn run(self, mut ctx: SlashCtx) -> CommandResult {
+        check::player_exist(&ctx)?;
+
+        let filters = ctx.player().get_player().await?.filters.unwrap_or_default();
+        let muted = ctx
+            .lavalink()
+            .get_connection(ctx.guild_id())
+            .is_some_and(|c| c.mute);
+
+        let data = ctx.player_data();
+        let data_r = data.read().await;
+        let volume = data_r.volume();
+        let pitch = data_r.pitch().clone();
+        let preset = data_r.filter_preset().map(|p| format!("🪄 `{}`", p.name()));
+        drop(data_r);
+
+        let volume_text = format!(
+            "{} `{}%`{}",
+            volume::volume_emoji((!muted).then_some(volume)),
+            volume,
+            volume::clipping_warning(volume)
+        );
+
+        let timescale = filters.timescale.unwrap_or_default();
+        let speed = match (timescale.speed, timescale.rate) {
+            (None, None) => None,
+            (speed, rate) => Some(speed.unwrap_or(1.) * rate.unwrap_or(1.)),
+        };
+        let speed_text = format!(
+            "{} `{}×`",
+            SpeedTier::from_multiplier(speed).emoji(),
+            speed.unwrap_or(1.)
+        );
+        let pitch_text = format!("{} {}", pitch.tier().emoji(), pitch);
+
+        let active_filters = active_filters(&filters);
+        let filters_text = if active_filters.is_empty() {
+            String::from(EMPTY_EMBED_FIELD)
+        } else {
+            active_filters.join("\n")
+        };
+        let equaliser_text = filters
+            .equalizer
+            .as_deref()
+            .map_or_else(|| String::from(EMPTY_EMBED_FIELD), equaliser_graph);
+
+        let embed = EmbedBuilder::new()
+            .title("🎛️ Tuning")
+            .color(EMBED_DEFAULT)
+            .field(EmbedFieldBuilder::new("Volume", volume_text).inline())
+            .field(EmbedFieldBuilder::new("Speed", speed_text).inline())
+            .field(EmbedFieldBuilder::new("Pitch", pitch_text).inline())
+            .field(EmbedFieldBuilder::new(
+                "Filter Preset",
+                preset.unwrap_or_else(|| String::from(EMPTY_EMBED_FIELD)),
+            ))
+            .field(EmbedFieldBuilder::new("Filters", filters_text))
+            .field(EmbedFieldBuilder::new("Equaliser", equaliser_text))
+            .validate()?
+            .build();
+
+        ctx.respond_embeds_only([embed]).await?;
+        Ok(())
+    }
+}
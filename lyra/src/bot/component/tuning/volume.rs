@@ -8,6 +8,11 @@ use std::num::NonZeroU16;
 use lyra_proc::BotCommandGroup;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
+use crate::bot::{
+    command::model::{Ctx, CtxKind},
+    gateway::ExpectedGuildIdAware,
+};
+
 pub(super) const fn volume_emoji(percent: Option<NonZeroU16>) -> &'static str {
     let Some(percent) = percent else {
         return "🔇";
@@ -27,6 +32,21 @@ pub fn clipping_warning(percent: NonZeroU16) -> &'static str {
         .unwrap_or_default()
 }
 
+/// The highest playback volume this guild allows, as configured in `/config defaults max-volume`
+async fn max_volume(ctx: &Ctx<impl CtxKind>) -> Result<NonZeroU16, sqlx::Error> {
+    let max_volume = sqlx::query!(
+        r"--sql
+        SELECT max_volume FROM guild_configs WHERE id = $1;
+        ",
+        ctx.guild_id().get() as i64,
+    )
+    .fetch_one(ctx.db())
+    .await?
+    .max_volume;
+
+    Ok(NonZeroU16::new(max_volume as u16).expect("max volume is non-zero"))
+}
+
 #[derive(CommandModel, CreateCommand, BotCommandGroup)]
 #[command(name = "volume", desc = ".", dm_permission = false)]
 pub enum Volume {
@@ -22,7 +22,7 @@ pub struct Down {
 
 impl BotSlashCommand for Down {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
 
         let lavalink = ctx.lavalink();
         let guild_id = ctx.guild_id();
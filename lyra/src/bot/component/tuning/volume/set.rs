@@ -3,11 +3,14 @@ use std::num::NonZeroU16;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::bot::{
-    command::{macros::out, model::BotSlashCommand, SlashCtx},
-    component::tuning::common_checks,
+    command::{
+        macros::{bad, out},
+        model::BotSlashCommand,
+        SlashCtx,
+    },
+    component::tuning::{common_checks, guard_loudness},
     error::CommandResult,
-    gateway::ExpectedGuildIdAware,
-    lavalink::{DelegateMethods, LavalinkAware},
+    lavalink::{ExpectedPlayerAware, ExpectedPlayerDataAware},
 };
 
 /// Set the playback volume
@@ -21,17 +24,26 @@ pub struct Set {
 
 impl BotSlashCommand for Set {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
-        common_checks(&ctx)?;
+        common_checks(&ctx).await?;
 
         let percent = NonZeroU16::new(self.percent as u16).expect("self.percent is non-zero");
-        let lavalink = ctx.lavalink();
-        let guild_id = ctx.guild_id();
-        lavalink.player(guild_id).set_volume(percent.get()).await?;
-        lavalink
-            .player_data(guild_id)
-            .write()
-            .await
-            .set_volume(percent);
+        let max_percent = super::max_volume(&ctx).await?;
+        if percent > max_percent {
+            bad!(
+                format!("Volume must not exceed this server's max volume of `{max_percent}%`."),
+                ctx
+            );
+        }
+
+        let player = ctx.player();
+        let data = ctx.player_data();
+        let filters = player.get_player().await?.filters.unwrap_or_default();
+        let Some(mut ctx) = guard_loudness(ctx, percent, &filters).await? else {
+            return Ok(());
+        };
+
+        player.set_volume(percent.get()).await?;
+        data.write().await.set_volume(percent);
 
         let emoji = super::volume_emoji(Some(percent));
         let warning = super::clipping_warning(percent);
@@ -8,7 +8,7 @@ use crate::bot::{
         model::BotSlashCommand,
         SlashCtx,
     },
-    component::tuning::unmuting_player_checks,
+    component::tuning::{guard_loudness, unmuting_player_checks},
     core::model::{BotStateAware, HttpAware},
     error::CommandResult,
     gateway::ExpectedGuildIdAware,
@@ -28,22 +28,20 @@ impl BotSlashCommand for Up {
     async fn run(self, mut ctx: SlashCtx) -> CommandResult {
         unmuting_player_checks(&ctx)?;
 
-        let lavalink = ctx.lavalink();
+        let bot = ctx.bot_owned();
+        let lavalink = bot.lavalink();
         let guild_id = ctx.guild_id();
         let data = &lavalink.player_data(guild_id);
         let percent_u16 = self.percent.unwrap_or(10) as u16;
 
-        let max_percent = NonZeroU16::new(1_000).expect("1_000 is non-zero");
-        let (old_percent_str, new_percent) = if lavalink.connection(guild_id).mute {
-            lavalink.connection_mut(guild_id).mute = false;
-            ctx.http()
-                .update_guild_member(guild_id, ctx.bot().user_id())
-                .mute(false)
-                .await?;
-
+        let max_percent = super::max_volume(&ctx).await?;
+        let muted = lavalink.connection(guild_id).mute;
+        let (old_percent_str, new_percent) = if muted {
             (
                 String::from("Muted"),
-                NonZeroU16::new(percent_u16).expect("self.percent is non-zero"),
+                NonZeroU16::new(percent_u16)
+                    .expect("self.percent is non-zero")
+                    .min(max_percent),
             )
         } else {
             let old_percent = data.read().await.volume();
@@ -58,6 +56,20 @@ impl BotSlashCommand for Up {
             )
         };
 
+        let player = lavalink.player(guild_id);
+        let filters = player.get_player().await?.filters.unwrap_or_default();
+        let Some(mut ctx) = guard_loudness(ctx, new_percent, &filters).await? else {
+            return Ok(());
+        };
+
+        if muted {
+            lavalink.connection_mut(guild_id).mute = false;
+            bot.http()
+                .update_guild_member(guild_id, bot.user_id())
+                .mute(false)
+                .await?;
+        }
+
         let emoji = super::volume_emoji(Some(new_percent));
         let warning = super::clipping_warning(new_percent);
 
@@ -65,10 +77,7 @@ impl BotSlashCommand for Up {
             .then_some(" (`Max`)")
             .unwrap_or_default();
 
-        lavalink
-            .player(guild_id)
-            .set_volume(new_percent.get())
-            .await?;
+        player.set_volume(new_percent.get()).await?;
         data.write().await.set_volume(new_percent);
 
         out!(
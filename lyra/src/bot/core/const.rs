@@ -69,14 +69,13 @@ pub mod metadata {
 pub mod connection {
     use std::time::Duration;
 
-    pub const INACTIVITY_TIMEOUT_SECS: u16 = 600;
-    pub const INACTIVITY_TIMEOUT_POLL_N: u8 = 10;
+    pub const INACTIVITY_TIMEOUT_POLL_N: u32 = 10;
+    pub const NETWORK_DROP_CLOSE_CODES: [u16; 4] = [1006, 4006, 4009, 4015];
 
     lazy_static::lazy_static! {
         pub static ref CONNECTION_CHANGED_TIMEOUT: Duration = Duration::from_millis(500);
         pub static ref GET_LAVALINK_CONNECTION_INFO_TIMEOUT: Duration = Duration::from_millis(2_000);
-        pub static ref INACTIVITY_TIMEOUT_POLL_INTERVAL: Duration =
-            Duration::from_secs(u64::from(INACTIVITY_TIMEOUT_SECS) / u64::from(INACTIVITY_TIMEOUT_POLL_N));
+        pub static ref WAIT_FOR_GUILD_CREATE_TIMEOUT: Duration = Duration::from_secs(30);
     }
 }
 
@@ -84,12 +83,28 @@ pub mod misc {
     use std::time::Duration;
 
     pub const ADD_TRACKS_WRAP_LIMIT: usize = 3;
+    pub const ADD_TRACKS_BACKGROUND_THRESHOLD: usize = 100;
+    pub const ADD_TRACKS_BACKGROUND_CHUNK_SIZE: usize = 50;
     pub const WAIT_FOR_NOT_SUPPRESSED_TIMEOUT_SECS: u8 = 30;
+    pub const EQUALISER_IMPORT_FILE_SIZE_LIMIT: u64 = 1 << 16;
+    pub const SAVED_EQUALISER_PRESETS_LIMIT: i64 = 25;
+    pub const FADE_SECS_MAX: i64 = 10;
+    pub const LOUDNESS_CONFIRMATION_THRESHOLD_DB: f64 = 12.;
+    pub const LOUDNESS_REFUSAL_THRESHOLD_DB: f64 = 24.;
+    pub const DISTORTION_ESTIMATED_GAIN_DB: f64 = 6.;
+    pub const SONG_REQUEST_UP_NEXT_LIMIT: usize = 10;
 
     lazy_static::lazy_static! {
         pub static ref WAIT_FOR_BOT_EVENTS_TIMEOUT: Duration = Duration::from_millis(1_000);
         pub static ref WAIT_FOR_NOT_SUPPRESSED_TIMEOUT: Duration = Duration::from_secs(WAIT_FOR_NOT_SUPPRESSED_TIMEOUT_SECS.into());
         pub static ref DESTRUCTIVE_COMMAND_CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(60);
+        pub static ref SEARCH_RESULTS_TIMEOUT: Duration = Duration::from_secs(60);
+        pub static ref DEFER_LOADING_TRACKS_AFTER: Duration = Duration::from_millis(2_000);
+        pub static ref ADD_TRACKS_BACKGROUND_CHUNK_INTERVAL: Duration = Duration::from_millis(1_000);
+        pub static ref FADE_STEP_INTERVAL: Duration = Duration::from_millis(250);
+        pub static ref FADE_POSITION_POLL_INTERVAL: Duration = Duration::from_secs(5);
+        pub static ref SONG_REQUEST_FEEDBACK_TIMEOUT: Duration = Duration::from_secs(5);
+        pub static ref EQUALISER_IMPORT_TIMEOUT: Duration = Duration::from_secs(10);
     }
 }
 
@@ -148,6 +163,9 @@ pub mod discord {
     pub const BASE_URL: &str = "https://discord.com";
     pub const CDN_URL: &str = "https://cdn.discordapp.com";
     pub const COMMAND_CHOICES_LIMIT: usize = 25;
+    pub const SELECT_MENU_OPTIONS_LIMIT: usize = 25;
+    pub const STAGE_TOPIC_LENGTH_LIMIT: usize = 120;
+    pub const VOICE_CHANNEL_STATUS_LENGTH_LIMIT: usize = 500;
 }
 
 pub mod colours {
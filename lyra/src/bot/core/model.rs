@@ -1,3 +1,4 @@
+mod guild_settings;
 mod interaction;
 
 use std::sync::{
@@ -8,7 +9,7 @@ use std::sync::{
 use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use sqlx::{Pool, Postgres};
-use twilight_cache_inmemory::InMemoryCache;
+use twilight_cache_inmemory::{InMemoryCache, ResourceType};
 use twilight_gateway::ShardId;
 use twilight_http::Client;
 use twilight_model::{
@@ -20,10 +21,12 @@ use twilight_model::{
 use twilight_standby::Standby;
 
 use crate::bot::{
+    component::{config::music_channel::MusicChannelSettings, queue::SongRequestChannel},
     error::core::DeserializeBodyFromHttpError,
     lavalink::{self, Lavalink},
 };
 
+pub use self::guild_settings::GuildSettingsCache;
 pub use self::interaction::{
     Client as InteractionClient, Interface as InteractionInterface, MessageResponse,
     UnitFollowupResult, UnitRespondResult,
@@ -129,6 +132,8 @@ pub struct BotState {
     lavalink: Lavalink,
     db: Pool<Postgres>,
     info: BotInfo,
+    song_request_channels: GuildSettingsCache<SongRequestChannel>,
+    music_channels: GuildSettingsCache<MusicChannelSettings>,
 }
 
 impl BotState {
@@ -139,12 +144,17 @@ impl BotState {
         };
 
         Self {
-            cache: InMemoryCache::new(),
+            // messages are only handled for song requests, so caching every one of them is wasteful
+            cache: InMemoryCache::builder()
+                .resource_types(ResourceType::all().difference(ResourceType::MESSAGE))
+                .build(),
             http,
             standby: Standby::new(),
             lavalink,
             db,
             info,
+            song_request_channels: GuildSettingsCache::default(),
+            music_channels: GuildSettingsCache::default(),
         }
     }
 
@@ -160,6 +170,14 @@ impl BotState {
         &self.info
     }
 
+    pub const fn song_request_channels(&self) -> &GuildSettingsCache<SongRequestChannel> {
+        &self.song_request_channels
+    }
+
+    pub const fn music_channels(&self) -> &GuildSettingsCache<MusicChannelSettings> {
+        &self.music_channels
+    }
+
     async fn app(&self) -> Result<Application, DeserializeBodyFromHttpError> {
         Ok(self.http.current_user_application().await?.model().await?)
     }
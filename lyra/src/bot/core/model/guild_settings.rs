@@ -0,0 +1,37 @@
+use std::future::Future;
+
+use dashmap::DashMap;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+/// Per-guild settings read on every message or interaction, kept in memory once fetched so that only changing them touches the database
+pub struct GuildSettingsCache<T>(DashMap<Id<GuildMarker>, Option<T>>);
+
+impl<T> Default for GuildSettingsCache<T> {
+    fn default() -> Self {
+        Self(DashMap::new())
+    }
+}
+
+impl<T: Copy> GuildSettingsCache<T> {
+    pub async fn get_or_fetch<E, F>(
+        &self,
+        guild_id: Id<GuildMarker>,
+        fetch: impl FnOnce() -> F,
+    ) -> Result<Option<T>, E>
+    where
+        F: Future<Output = Result<Option<T>, E>>,
+    {
+        if let Some(settings) = self.0.get(&guild_id).as_deref().copied() {
+            return Ok(settings);
+        }
+
+        let settings = fetch().await?;
+        self.0.insert(guild_id, settings);
+        Ok(settings)
+    }
+
+    /// Forgets the guild's settings, so that they are fetched anew the next time they are read
+    pub fn invalidate(&self, guild_id: Id<GuildMarker>) {
+        self.0.remove(&guild_id);
+    }
+}
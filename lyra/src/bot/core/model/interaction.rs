@@ -2,7 +2,7 @@ use twilight_http::{request::application::interaction::UpdateResponse, Response}
 use twilight_model::{
     application::{command::CommandOptionChoice, interaction::Interaction},
     channel::{
-        message::{component::ActionRow, AllowedMentions, Embed, MessageFlags},
+        message::{component::ActionRow, AllowedMentions, Component, Embed, MessageFlags},
         Message,
     },
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
@@ -76,6 +76,34 @@ impl Interface<'_> {
         self.inner.response(interaction_token).await
     }
 
+    pub async fn defer(&self) -> UnitRespondResult {
+        self.inner
+            .create_response(
+                self.interaction_id,
+                self.interaction_token(),
+                &InteractionResponse {
+                    kind: InteractionResponseType::DeferredChannelMessageWithSource,
+                    data: None,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn defer_update(&self) -> UnitRespondResult {
+        self.inner
+            .create_response(
+                self.interaction_id,
+                self.interaction_token(),
+                &InteractionResponse {
+                    kind: InteractionResponseType::DeferredUpdateMessage,
+                    data: None,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
     fn update(&self) -> UpdateResponse<'_> {
         self.inner.update_response(self.interaction_token())
     }
@@ -89,6 +117,34 @@ impl Interface<'_> {
             .await?)
     }
 
+    pub async fn update_no_components(&self) -> MessageFollowupResult {
+        Ok(self.update().components(None).await?)
+    }
+
+    pub async fn update_content_and_components(
+        &self,
+        content: &str,
+        components: &[Component],
+    ) -> MessageFollowupResult {
+        Ok(self
+            .update()
+            .content(Some(content))
+            .components(Some(components))
+            .await?)
+    }
+
+    pub async fn update_embeds_and_components(
+        &self,
+        embeds: &[Embed],
+        components: &[Component],
+    ) -> MessageFollowupResult {
+        Ok(self
+            .update()
+            .embeds(Some(embeds))
+            .components(Some(components))
+            .await?)
+    }
+
     pub async fn update_message_embeds_only(
         &self,
         embeds: impl IntoIterator<Item = Embed> + Send,
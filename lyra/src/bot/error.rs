@@ -96,6 +96,37 @@ pub enum Suppressed {
     NotSpeaker,
 }
 
+#[derive(Error, Debug)]
+pub enum TuningRestricted {
+    #[error("tuning feature is disabled by server configuration: {}", .0)]
+    Disabled(&'static str),
+    #[error("{} is out of the bounds set by server configuration", .setting)]
+    OutOfBounds {
+        setting: &'static str,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+}
+
+impl EPrint for TuningRestricted {
+    fn eprint(&self) -> String {
+        match self {
+            Self::Disabled(feature) => {
+                format!("**`{feature}`** is disabled by server configuration.")
+            }
+            Self::OutOfBounds { setting, min, max } => {
+                let bounds = match (min, max) {
+                    (Some(min), Some(max)) => format!("between `{min}`× and `{max}`×"),
+                    (Some(min), None) => format!("at least `{min}`×"),
+                    (None, Some(max)) => format!("at most `{max}`×"),
+                    (None, None) => unreachable!("out of bounds requires a bound"),
+                };
+                format!("{setting} must be {bounds} by server configuration.")
+            }
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 #[error("bot is not playing anything")]
 pub struct NotPlaying;
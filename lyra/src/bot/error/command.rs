@@ -30,6 +30,7 @@ pub enum Error {
     CheckNotSuppressed(#[from] check::NotSuppressedError),
     CheckUsersTrack(#[from] check::UsersTrackError),
     UserNotDj(#[from] super::UserNotDj),
+    TuningRestricted(#[from] super::TuningRestricted),
     InVoiceWithSomeoneElse(#[from] check::InVoiceWithSomeoneElseError),
     PositionOutOfRange(#[from] super::PositionOutOfRange),
     CheckRun(#[from] check::RunError),
@@ -43,10 +44,13 @@ pub enum Error {
     ),
     Play(#[from] super::component::queue::play::Error),
     DeserializeBodyFromHttp(#[from] super::core::DeserializeBodyFromHttpError),
+    DeserializeBody(#[from] twilight_http::response::DeserializeBodyError),
     RemoveTracks(#[from] super::component::queue::RemoveTracksError),
     TwilightHttp(#[from] twilight_http::Error),
     Lavalink(#[from] lavalink_rs::error::LavalinkError),
     NoPlayer(#[from] super::lavalink::NoPlayerError),
+    Reqwest(#[from] reqwest::Error),
+    GatewaySend(#[from] twilight_gateway::error::ChannelError),
 }
 
 pub enum FlattenedError<'a> {
@@ -62,6 +66,7 @@ pub enum FlattenedError<'a> {
     Suppressed(&'a super::Suppressed),
     NotUsersTrack(&'a super::NotUsersTrack),
     UserNotDj(&'a super::UserNotDj),
+    TuningRestricted(&'a super::TuningRestricted),
     InVoiceWithoutSomeoneElse(&'a super::InVoiceWithoutSomeoneElse),
     NotPlaying(&'a super::NotPlaying),
     Paused(&'a super::Paused),
@@ -84,6 +89,7 @@ pub enum FlattenedError<'a> {
     AutoJoinAttemptFailed(&'a super::AutoJoinAttemptFailed),
     Lavalink(&'a lavalink_rs::error::LavalinkError),
     NoPlayer(&'a super::lavalink::NoPlayerError),
+    Reqwest(&'a reqwest::Error),
 }
 
 pub use FlattenedError as Fe;
@@ -288,6 +294,9 @@ impl<'a> Fe<'a> {
             super::component::connection::join::ResidualImplConnectToError::Lavalink(e) => {
                 Self::Lavalink(e)
             }
+            super::component::connection::join::ResidualImplConnectToError::Sqlx(e) => {
+                Self::Sqlx(e)
+            }
             super::component::connection::join::ResidualImplConnectToError::CheckUserAllowed(e) => {
                 Self::from_check_user_allowed_residual(e)
             }
@@ -344,6 +353,7 @@ impl<'a> Fe<'a> {
             super::component::connection::join::HandleResponseError::Followup(e) => {
                 Self::from_followup(e)
             }
+            super::component::connection::join::HandleResponseError::Sqlx(e) => Self::Sqlx(e),
         }
     }
 
@@ -389,6 +399,7 @@ impl<'a> Fe<'a> {
             super::component::connection::leave::ResidualError::PreDisconnectCleanupError(e) => {
                 Self::from_pre_disconnect_cleanup(e)
             }
+            super::component::connection::leave::ResidualError::Respond(e) => Self::from_respond(e),
         }
     }
 
@@ -433,6 +444,7 @@ impl<'a> Fe<'a> {
     const fn from_impl_connect_to_residual_2(error: &'a util::ResidualImplConnectToError) -> Self {
         match error {
             util::ResidualImplConnectToError::Lavalink(e) => Self::Lavalink(e),
+            util::ResidualImplConnectToError::Sqlx(e) => Self::Sqlx(e),
             util::ResidualImplConnectToError::Cache(e) => Self::Cache(e),
             util::ResidualImplConnectToError::GatewaySend(e) => Self::GatewaySend(e),
             util::ResidualImplConnectToError::TwilightHttp(e) => Self::TwilightHttp(e),
@@ -488,6 +500,13 @@ impl<'a> Fe<'a> {
         }
     }
 
+    const fn from_auto_new_player_data(error: &'a util::AutoNewPlayerDataError) -> Fe<'a> {
+        match error {
+            util::AutoNewPlayerDataError::Lavalink(e) => Self::Lavalink(e),
+            util::AutoNewPlayerDataError::Sqlx(e) => Self::Sqlx(e),
+        }
+    }
+
     const fn from_play(error: &'a super::component::queue::play::Error) -> Fe<'a> {
         match error {
             super::component::queue::play::Error::Lavalink(e) => Self::Lavalink(e),
@@ -499,6 +518,10 @@ impl<'a> Fe<'a> {
             super::component::queue::play::Error::AutoJoinOrCheckInVoiceWithUser(e) => {
                 Self::from_auto_join_or_check_in_voice_with_user(e)
             }
+            super::component::queue::play::Error::DeserializeBody(e) => Self::DeserializeBody(e),
+            super::component::queue::play::Error::AutoNewPlayerData(e) => {
+                Self::from_auto_new_player_data(e)
+            }
         }
     }
 
@@ -528,9 +551,12 @@ impl Error {
             Self::QueueEmpty(e) => Fe::QueueEmpty(e),
             Self::PositionOutOfRange(e) => Fe::PositionOutOfRange(e),
             Self::UserNotDj(e) => Fe::UserNotDj(e),
+            Self::TuningRestricted(e) => Fe::TuningRestricted(e),
             Self::TwilightHttp(e) => Fe::TwilightHttp(e),
             Self::Lavalink(e) => Fe::Lavalink(e),
             Self::NoPlayer(e) => Fe::NoPlayer(e),
+            Self::Reqwest(e) => Fe::Reqwest(e),
+            Self::GatewaySend(e) => Fe::GatewaySend(e),
             Self::CheckNotSuppressed(e) => Fe::from_check_not_suppressed_error(e),
             Self::CheckUsersTrack(e) => Fe::from_users_track_error(e),
             Self::InVoiceWithSomeoneElse(e) => Fe::from_in_voice_with_someone_else_error(e),
@@ -543,6 +569,7 @@ impl Error {
             Self::WithAdvanceLockAndStopped(e) => Fe::from_with_advance_lock_and_stopped(e),
             Self::Play(e) => Fe::from_play(e),
             Self::DeserializeBodyFromHttp(e) => Fe::from_deserialize_body_from_http_error(e),
+            Self::DeserializeBody(e) => Fe::DeserializeBody(e),
             Self::RemoveTracks(e) => Fe::from_remove_tracks(e),
         }
     }
@@ -556,6 +583,7 @@ pub enum AutocompleteError {
     LoadFailed(#[from] super::LoadFailed),
     Respond(#[from] RespondError),
     Lavalink(#[from] lavalink_rs::error::LavalinkError),
+    Sqlx(#[from] sqlx::Error),
 }
 
 pub type AutocompleteResult = core::result::Result<(), AutocompleteError>;
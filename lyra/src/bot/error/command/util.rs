@@ -37,6 +37,7 @@ pub enum ResidualImplConnectToError {
     GatewaySend(#[from] twilight_gateway::error::ChannelError),
     TwilightHttp(#[from] twilight_http::Error),
     Lavalink(#[from] lavalink_rs::error::LavalinkError),
+    Sqlx(#[from] sqlx::Error),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -108,6 +109,9 @@ impl AutoJoinAttemptError {
             crate::bot::error::component::connection::join::ImplConnectToError::TwilightHttp(e) => {
                 Self::ImplAutoJoin(ResidualImplAutoJoinError::ConnectToNew(ResidualConnectToNewError::ImplConnectTo(ResidualImplConnectToError::TwilightHttp(e))))
             },
+            crate::bot::error::component::connection::join::ImplConnectToError::Sqlx(e) => {
+                Self::ImplAutoJoin(ResidualImplAutoJoinError::ConnectToNew(ResidualConnectToNewError::ImplConnectTo(ResidualImplConnectToError::Sqlx(e))))
+            },
             crate::bot::error::component::connection::join::ImplConnectToError::CheckUserAllowed(e) => {
                 Self::from_check_user_allowed(e)
             },
@@ -174,3 +178,10 @@ pub enum AutoJoinSuppressedError {
     #[error("bot has still not become a speaker in stage")]
     StillNotSpeaker { last_followup_id: Id<MessageMarker> },
 }
+
+#[derive(thiserror::Error, Debug)]
+#[error(transparent)]
+pub enum AutoNewPlayerDataError {
+    Lavalink(#[from] lavalink_rs::error::LavalinkError),
+    Sqlx(#[from] sqlx::Error),
+}
@@ -26,6 +26,7 @@ pub mod join {
         GatewaySend(#[from] twilight_gateway::error::ChannelError),
         TwilightHttp(#[from] twilight_http::Error),
         Lavalink(#[from] lavalink_rs::error::LavalinkError),
+        Sqlx(#[from] sqlx::Error),
     }
 
     #[derive(thiserror::Error, Debug)]
@@ -44,6 +45,17 @@ pub mod join {
         ImplConnectTo(#[from] ImplConnectToError),
     }
 
+    #[derive(thiserror::Error, Debug)]
+    #[error(transparent)]
+    pub enum FollowError {
+        UserNotStageManager(#[from] crate::bot::error::UserNotStageManager),
+        Forbidden(#[from] crate::bot::error::ConnectionForbidden),
+        CheckUserAllowed(#[from] check::UserAllowedError),
+        Cache(#[from] crate::bot::error::Cache),
+        CachePermissions(#[from] twilight_cache_inmemory::permission::ChannelError),
+        ImplConnectTo(#[from] ImplConnectToError),
+    }
+
     #[derive(thiserror::Error, Debug)]
     #[error(transparent)]
     pub enum ImplJoinError {
@@ -65,6 +77,7 @@ pub mod join {
         Respond(#[from] crate::bot::error::command::RespondError),
         DeserializeBody(#[from] twilight_http::response::DeserializeBodyError),
         Followup(#[from] crate::bot::error::command::FollowupError),
+        Sqlx(#[from] sqlx::Error),
     }
 
     #[derive(thiserror::Error, Debug)]
@@ -125,6 +138,7 @@ pub mod join {
         GatewaySend(#[from] twilight_gateway::error::ChannelError),
         TwilightHttp(#[from] twilight_http::Error),
         Lavalink(#[from] lavalink_rs::error::LavalinkError),
+        Sqlx(#[from] sqlx::Error),
     }
 
     #[derive(thiserror::Error, Debug)]
@@ -159,6 +173,9 @@ pub mod join {
                 HandleResponseError::Followup(e) => Self::Other(ResidualError::HandleResponse(
                     HandleResponseError::Followup(e),
                 )),
+                HandleResponseError::Sqlx(e) => {
+                    Self::Other(ResidualError::HandleResponse(HandleResponseError::Sqlx(e)))
+                }
             }
         }
 
@@ -203,6 +220,11 @@ pub mod join {
                         ResidualImplConnectToError::Lavalink(e),
                     )),
                 )),
+                ImplConnectToError::Sqlx(e) => {
+                    Self::Other(ResidualError::ImplJoin(ResidualImplJoinError::ConnectTo(
+                        ResidualConnectToError::ImplConnectTo(ResidualImplConnectToError::Sqlx(e)),
+                    )))
+                }
                 ImplConnectToError::CheckUserAllowed(e) => Self::from_check_user_allowed(e),
             }
         }
@@ -266,6 +288,7 @@ pub mod leave {
         CheckUserOnlyIn(#[from] crate::bot::error::command::check::UserOnlyInError),
         PreDisconnectCleanup(#[from] PreDisconnectCleanupError),
         GatewaySend(#[from] twilight_gateway::error::ChannelError),
+        Respond(#[from] crate::bot::error::command::RespondError),
     }
 
     impl Error {
@@ -284,6 +307,7 @@ pub mod leave {
                 Self::GatewaySend(e) => {
                     NotInVoiceMatchedError::Other(ResidualError::GatewaySend(e))
                 }
+                Self::Respond(e) => NotInVoiceMatchedError::Other(ResidualError::Respond(e)),
             }
         }
     }
@@ -300,6 +324,7 @@ pub mod leave {
         CheckUserOnlyIn(#[from] crate::bot::error::command::check::UserOnlyInError),
         PreDisconnectCleanupError(#[from] PreDisconnectCleanupError),
         GatewaySend(#[from] twilight_gateway::error::ChannelError),
+        Respond(#[from] crate::bot::error::command::RespondError),
     }
 }
 
@@ -315,6 +340,73 @@ pub enum StartInactivityTimeoutError {
     PreDisconnectCleanup(#[from] leave::PreDisconnectCleanupError),
 }
 
+#[derive(Error, Debug)]
+#[error("following user failed: {:?}", .0)]
+pub enum FollowUserError {
+    Sqlx(#[from] sqlx::Error),
+    Http(#[from] twilight_http::Error),
+    CachePermissions(#[from] twilight_cache_inmemory::permission::ChannelError),
+    Follow(#[from] join::FollowError),
+}
+
+#[derive(Error, Debug)]
+#[error("handling channel deletion failed: {:?}", .0)]
+pub enum HandleChannelDeletionError {
+    GatewaySend(#[from] twilight_gateway::error::ChannelError),
+    Http(#[from] twilight_http::Error),
+    PreDisconnectCleanup(#[from] leave::PreDisconnectCleanupError),
+}
+
+#[derive(Error, Debug)]
+#[error("reconnecting to voice failed: {:?}", .0)]
+pub enum ReconnectError {
+    Sqlx(#[from] sqlx::Error),
+    Lavalink(#[from] lavalink_rs::error::LavalinkError),
+    GatewaySend(#[from] twilight_gateway::error::ChannelError),
+    Http(#[from] twilight_http::Error),
+    PreDisconnectCleanup(#[from] leave::PreDisconnectCleanupError),
+}
+
+#[derive(Error, Debug)]
+#[error("waiting to reconnect to voice failed: {:?}", .0)]
+pub enum WaitToReconnectError {
+    Http(#[from] twilight_http::Error),
+    DeserializeBody(#[from] twilight_http::response::DeserializeBodyError),
+    DeserializeBodyFromHttp(#[from] crate::bot::error::core::DeserializeBodyFromHttpError),
+    Reconnect(#[from] ReconnectError),
+}
+
+#[derive(Error, Debug)]
+#[error("rejoining 24/7 voice failed: {:?}", .0)]
+pub enum RejoinTwentyFourSevenError {
+    Sqlx(#[from] sqlx::Error),
+    Lavalink(#[from] lavalink_rs::error::LavalinkError),
+    Http(#[from] twilight_http::Error),
+    Reconnect(#[from] ReconnectError),
+}
+
+#[derive(Error, Debug)]
+#[error("updating channel status failed: {:?}", .0)]
+pub enum UpdateChannelStatusError {
+    Sqlx(#[from] sqlx::Error),
+    Http(#[from] twilight_http::Error),
+    DeserializeBody(#[from] twilight_http::response::DeserializeBodyError),
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Error, Debug)]
+#[error("restoring channel status failed: {:?}", .0)]
+pub enum RestoreChannelStatusError {
+    Http(#[from] twilight_http::Error),
+}
+
+#[derive(Error, Debug)]
+#[error(transparent)]
+pub enum PauseWhenAloneError {
+    Lavalink(#[from] lavalink_rs::error::LavalinkError),
+    Http(#[from] twilight_http::Error),
+}
+
 #[derive(Error, Debug)]
 #[error("handling `VoiceStateUpdate` failed: {:?}", .0)]
 pub enum HandleVoiceStateUpdateError {
@@ -323,6 +415,11 @@ pub enum HandleVoiceStateUpdateError {
     MessageValidation(#[from] twilight_validate::message::MessageValidationError),
     MatchStateChannelID(#[from] MatchStateChannelIdError),
     PreDisconnectCleanup(#[from] leave::PreDisconnectCleanupError),
+    PauseWhenAlone(#[from] PauseWhenAloneError),
+    Sqlx(#[from] sqlx::Error),
+    Lavalink(#[from] lavalink_rs::error::LavalinkError),
+    WaitToReconnect(#[from] WaitToReconnectError),
+    FollowUser(#[from] FollowUserError),
 }
 
 #[derive(Error, Debug)]
@@ -331,4 +428,6 @@ pub enum MatchStateChannelIdError {
     Http(#[from] twilight_http::Error),
     MessageValidation(#[from] twilight_validate::message::MessageValidationError),
     Cache(#[from] crate::bot::error::Cache),
+    PauseWhenAlone(#[from] PauseWhenAloneError),
+    Sqlx(#[from] sqlx::Error),
 }
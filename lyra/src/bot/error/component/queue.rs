@@ -26,6 +26,8 @@ pub mod play {
             #[from] crate::bot::error::command::util::AutoJoinOrCheckInVoiceWithUserError,
         ),
         Lavalink(#[from] lavalink_rs::error::LavalinkError),
+        DeserializeBody(#[from] twilight_http::response::DeserializeBodyError),
+        AutoNewPlayerData(#[from] crate::bot::error::command::util::AutoNewPlayerDataError),
     }
 }
 
@@ -52,3 +54,24 @@ pub enum RemoveTracksError {
     #[error(transparent)]
     DeserializeBodyFromHttp(#[from] crate::bot::error::core::DeserializeBodyFromHttpError),
 }
+
+#[derive(Error, Debug)]
+#[error("updating song request message failed: {:?}", .0)]
+pub enum UpdateSongRequestMessageError {
+    Sqlx(#[from] sqlx::Error),
+    Http(#[from] twilight_http::Error),
+}
+
+#[derive(Error, Debug)]
+#[error("handling song request failed: {:?}", .0)]
+pub enum HandleSongRequestError {
+    Sqlx(#[from] sqlx::Error),
+    Http(#[from] twilight_http::Error),
+    DeserializeBody(#[from] twilight_http::response::DeserializeBodyError),
+    CachePermissions(#[from] twilight_cache_inmemory::permission::ChannelError),
+    Cache(#[from] crate::bot::error::Cache),
+    CheckUserAllowed(#[from] crate::bot::error::command::check::UserAllowedError),
+    Lavalink(#[from] lavalink_rs::error::LavalinkError),
+    Reconnect(#[from] crate::bot::error::component::connection::ReconnectError),
+    UpdateSongRequestMessage(#[from] UpdateSongRequestMessageError),
+}
@@ -0,0 +1,22 @@
+pub mod equaliser {
+    #[derive(thiserror::Error, Debug)]
+    pub enum ParseCurveError {
+        #[error("no `GraphicEQ:` line or parametric filters were found")]
+        Unrecognised,
+        #[error("`{}` is not a valid `GraphicEQ:` point", .0)]
+        GraphicPoint(Box<str>),
+        #[error("`{}` is not a valid parametric filter", .0)]
+        Filter(Box<str>),
+        #[error("`{}` filters are not supported", .0)]
+        UnsupportedFilter(Box<str>),
+    }
+}
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[error("handling `VoiceStateUpdate` failed: {:?}", .0)]
+pub enum HandleVoiceStateUpdateError {
+    Http(#[from] twilight_http::Error),
+    Lavalink(#[from] lavalink_rs::error::LavalinkError),
+}
@@ -26,6 +26,14 @@ pub enum ProcessError {
     #[error(transparent)]
     HandleVoiceStateUpdate(#[from] super::component::connection::HandleVoiceStateUpdateError),
     #[error(transparent)]
+    HandleTuningVoiceStateUpdate(#[from] super::component::tuning::HandleVoiceStateUpdateError),
+    #[error(transparent)]
+    HandleChannelDeletion(#[from] super::component::connection::HandleChannelDeletionError),
+    #[error(transparent)]
+    PreDisconnectCleanup(#[from] super::component::connection::leave::PreDisconnectCleanupError),
+    #[error(transparent)]
+    HandleSongRequest(#[from] super::component::queue::HandleSongRequestError),
+    #[error(transparent)]
     MatchConfirmation(#[from] MatchConfirmationError),
     #[error(transparent)]
     Respond(#[from] super::command::RespondError),
@@ -8,6 +8,11 @@ pub struct NoPlayerError;
 #[error("processing lavalink event failed: {:?}", .0)]
 pub enum ProcessError {
     Lavalink(#[from] lavalink_rs::error::LavalinkError),
+    Sqlx(#[from] sqlx::Error),
+    TwilightHttp(#[from] twilight_http::Error),
+    DeserializeBody(#[from] twilight_http::response::DeserializeBodyError),
+    EmbedValidation(#[from] twilight_validate::embed::EmbedValidationError),
+    Reconnect(#[from] crate::bot::error::component::connection::ReconnectError),
 }
 
 pub type ProcessResult = Result<(), ProcessError>;
@@ -1,7 +1,10 @@
+mod channel;
 mod guild;
 mod interaction;
+pub mod message;
 mod model;
 mod process;
+mod role;
 mod shard;
 pub mod voice;
 
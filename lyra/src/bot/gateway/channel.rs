@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+use super::model::Process;
+use crate::bot::{
+    component::{config::access, connection},
+    core::model::{BotState, OwnedBotState},
+    error::gateway::ProcessResult,
+};
+
+pub(super) struct DeleteContext {
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+    bot: OwnedBotState,
+}
+
+impl BotState {
+    /// Used for both channel and thread deletions, as threads are access controlled like channels
+    pub(super) const fn into_channel_delete_context(
+        self: Arc<Self>,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> DeleteContext {
+        DeleteContext {
+            guild_id,
+            channel_id,
+            bot: self,
+        }
+    }
+}
+
+impl DeleteContext {
+    async fn unbind_channel_settings(&self) -> Result<(), sqlx::Error> {
+        let channel_id = self.channel_id.get() as i64;
+        sqlx::query!(
+            r"--sql
+            UPDATE guild_configs
+            SET
+                music_channel = NULLIF(music_channel, $2),
+                music_channel_only = music_channel_only AND music_channel IS DISTINCT FROM $2,
+                song_request_channel = NULLIF(song_request_channel, $2),
+                song_request_message = CASE
+                    WHEN song_request_channel = $2 THEN NULL
+                    ELSE song_request_message
+                END,
+                twenty_four_seven = twenty_four_seven
+                    AND twenty_four_seven_channel IS DISTINCT FROM $2
+                    AND twenty_four_seven_text_channel IS DISTINCT FROM $2
+            WHERE id = $1;
+            ",
+            self.guild_id.get() as i64,
+            channel_id,
+        )
+        .execute(self.bot.db())
+        .await?;
+        self.bot.song_request_channels().invalidate(self.guild_id);
+        self.bot.music_channels().invalidate(self.guild_id);
+        Ok(())
+    }
+}
+
+impl Process for DeleteContext {
+    async fn process(self) -> ProcessResult {
+        connection::handle_channel_deletion(self.bot.clone(), self.guild_id, self.channel_id)
+            .await?;
+        access::prune_channel(self.bot.db(), self.guild_id, self.channel_id).await?;
+        self.unbind_channel_settings().await?;
+
+        Ok(())
+    }
+}
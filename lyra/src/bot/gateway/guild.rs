@@ -1,9 +1,12 @@
+use std::sync::Arc;
+
 use twilight_gateway::ShardId;
 use twilight_model::gateway::payload::incoming::{GuildCreate, GuildDelete};
 
 use super::model::Process;
 use crate::bot::{
-    core::model::{BotState, BotStateRef},
+    component::connection,
+    core::model::{BotState, BotStateRef, OwnedBotState},
     error::gateway::ProcessResult,
 };
 
@@ -50,9 +53,9 @@ impl Process for CreateContext<'_> {
 }
 
 pub(super) struct DeleteContext<'a> {
-    _inner: &'a GuildDelete,
+    inner: &'a GuildDelete,
     shard_id: ShardId,
-    bot: BotStateRef<'a>,
+    bot: &'a OwnedBotState,
 }
 
 impl BotState {
@@ -69,12 +72,12 @@ impl BotState {
     }
 
     pub(super) const fn as_guild_delete_context<'a>(
-        &'a self,
+        self: &'a Arc<Self>,
         inner: &'a GuildDelete,
         shard_id: ShardId,
-    ) -> DeleteContext {
+    ) -> DeleteContext<'a> {
         DeleteContext {
-            _inner: inner,
+            inner,
             shard_id,
             bot: self,
         }
@@ -90,6 +93,11 @@ impl DeleteContext<'_> {
 impl Process for DeleteContext<'_> {
     async fn process(self) -> ProcessResult {
         self.decrement_guild_count();
+        if !self.inner.unavailable {
+            self.bot.song_request_channels().invalidate(self.inner.id);
+            self.bot.music_channels().invalidate(self.inner.id);
+            connection::handle_guild_removal(self.bot.clone(), self.inner.id).await?;
+        }
 
         Ok(())
     }
@@ -17,7 +17,7 @@ use crate::bot::{
         util::MessageLinkAware,
         AutocompleteCtx, MessageCtx, SlashCtx,
     },
-    component::{connection::Join, queue::Play},
+    component::{config::music_channel::MusicChannelSettings, connection::Join, queue::Play},
     core::{
         model::{
             BotState, InteractionClient, InteractionInterface, OwnedBotState, UnitFollowupResult,
@@ -94,6 +94,22 @@ impl Context {
             .map(|c| c.id)
             .expect("interaction type is not ping");
 
+        let music_channel = match inner_guild_id {
+            Some(guild_id) => MusicChannelSettings::get(&bot, guild_id).await?,
+            None => None,
+        };
+        if let Some(ref music_channel) = music_channel {
+            if data.name != "config" && music_channel.rejects(channel_id) {
+                nope!(
+                    format!(
+                        "Commands can only be used in {} in this server.",
+                        music_channel.channel_id().mention()
+                    ),
+                    i
+                );
+            }
+        }
+
         let result = match data.kind {
             CommandType::ChatInput => {
                 SlashCtx::from_partial_data(
@@ -123,12 +139,13 @@ impl Context {
 
         if let Some(guild_id) = inner_guild_id {
             let lavalink = bot.lavalink();
+            let text_channel_id = music_channel.map_or(channel_id, |m| m.channel_id());
 
             if lavalink
                 .get_connection(guild_id)
-                .is_some_and(|c| c.text_channel_id != channel_id)
+                .is_some_and(|c| c.text_channel_id != text_channel_id)
             {
-                lavalink.connection_mut(guild_id).text_channel_id = channel_id;
+                lavalink.connection_mut(guild_id).text_channel_id = text_channel_id;
             }
         }
 
@@ -198,6 +215,9 @@ async fn match_error(
         Fe::UserNotDj(_) => {
             nope!("You need to be a ***DJ*** to do that.", i);
         }
+        Fe::TuningRestricted(e) => {
+            nope!(e.eprint(), i);
+        }
         Fe::UserNotAccessManager(_) => {
             nope!("You need to be an ***Access Manager*** to do that.", i);
         }
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use twilight_cache_inmemory::InMemoryCache;
+use twilight_gateway::MessageSender;
+use twilight_http::Client;
+use twilight_model::{
+    gateway::payload::incoming::MessageCreate,
+    id::{marker::GuildMarker, Id},
+};
+
+use crate::bot::{
+    component::queue,
+    core::model::{BotState, BotStateAware, CacheAware, HttpAware, OwnedBotStateAware},
+    error::gateway::ProcessResult,
+    gateway::{ExpectedGuildIdAware, SenderAware},
+    lavalink::{Lavalink, LavalinkAware},
+};
+
+use super::Process;
+
+pub struct Context {
+    pub inner: Box<MessageCreate>,
+    bot: Arc<BotState>,
+    sender: MessageSender,
+}
+
+impl BotState {
+    pub(super) const fn into_message_create_context(
+        self: Arc<Self>,
+        inner: Box<MessageCreate>,
+        sender: MessageSender,
+    ) -> Context {
+        Context {
+            inner,
+            bot: self,
+            sender,
+        }
+    }
+}
+
+impl BotStateAware for Context {
+    fn bot(&self) -> &BotState {
+        &self.bot
+    }
+}
+
+impl OwnedBotStateAware for Context {
+    fn bot_owned(&self) -> Arc<BotState> {
+        self.bot.clone()
+    }
+}
+
+impl CacheAware for Context {
+    fn cache(&self) -> &InMemoryCache {
+        self.bot.cache()
+    }
+}
+
+impl LavalinkAware for Context {
+    fn lavalink(&self) -> &Lavalink {
+        self.bot.lavalink()
+    }
+}
+
+impl HttpAware for Context {
+    fn http(&self) -> &Client {
+        self.bot.http()
+    }
+}
+
+impl SenderAware for Context {
+    fn sender(&self) -> &MessageSender {
+        &self.sender
+    }
+}
+
+impl ExpectedGuildIdAware for Context {
+    fn guild_id(&self) -> Id<GuildMarker> {
+        self.inner.guild_id.expect("message was sent in a guild")
+    }
+}
+
+impl Process for Context {
+    async fn process(self) -> ProcessResult {
+        if self.inner.guild_id.is_none() || self.inner.author.bot {
+            return Ok(());
+        }
+
+        queue::handle_song_request(&self).await?;
+        Ok(())
+    }
+}
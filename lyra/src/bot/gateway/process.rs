@@ -14,14 +14,29 @@ pub async fn process(
     sender: MessageSender,
 ) -> ProcessResult {
     match event {
-        Event::Ready(ref e) => bot.as_ready_context(e, shard_id).process().await,
+        Event::Ready(e) => bot.into_ready_context(e, shard_id, sender).process().await,
         Event::GuildCreate(ref e) => bot.as_guild_create_context(e, shard_id).process().await,
         Event::GuildDelete(ref e) => bot.as_guild_delete_context(e, shard_id).process().await,
+        Event::ChannelDelete(e) => {
+            let Some(guild_id) = e.guild_id else {
+                return Ok(());
+            };
+            bot.into_channel_delete_context(guild_id, e.id)
+                .process()
+                .await
+        }
+        Event::ThreadDelete(e) => {
+            bot.into_channel_delete_context(e.guild_id, e.id)
+                .process()
+                .await
+        }
+        Event::RoleDelete(ref e) => bot.as_role_delete_context(e).process().await,
         Event::InteractionCreate(e) => {
             bot.into_interaction_create_context(e, latency, sender)
                 .process()
                 .await
         }
+        Event::MessageCreate(e) => bot.into_message_create_context(e, sender).process().await,
         Event::VoiceStateUpdate(e) => {
             bot.into_voice_state_update_context(e, states, sender)
                 .process()
@@ -0,0 +1,30 @@
+use twilight_model::gateway::payload::incoming::RoleDelete;
+
+use super::model::Process;
+use crate::bot::{
+    component::config::access,
+    core::model::{BotState, BotStateRef},
+    error::gateway::ProcessResult,
+};
+
+pub(super) struct DeleteContext<'a> {
+    inner: &'a RoleDelete,
+    bot: BotStateRef<'a>,
+}
+
+impl BotState {
+    pub(super) const fn as_role_delete_context<'a>(
+        &'a self,
+        inner: &'a RoleDelete,
+    ) -> DeleteContext<'a> {
+        DeleteContext { inner, bot: self }
+    }
+}
+
+impl Process for DeleteContext<'_> {
+    async fn process(self) -> ProcessResult {
+        access::prune_role(self.bot.db(), self.inner.guild_id, self.inner.role_id).await?;
+
+        Ok(())
+    }
+}
@@ -1,35 +1,78 @@
+use std::sync::Arc;
+
 use tokio::task::JoinSet;
-use twilight_gateway::ShardId;
-use twilight_model::gateway::payload::incoming::Ready;
+use twilight_gateway::{MessageSender, ShardId};
+use twilight_model::{gateway::payload::incoming::Ready, id::Id};
 
 use crate::bot::{
-    core::model::{BotState, BotStateRef},
+    component::connection,
+    core::{model::BotState, traced},
     error::gateway::ProcessResult,
+    lavalink::{DelegateMethods, LavalinkAware},
 };
 
 use super::model::Process;
 
-pub(super) struct ReadyContext<'a> {
-    inner: &'a Ready,
+pub(super) struct ReadyContext {
+    inner: Box<Ready>,
     shard_id: ShardId,
-    bot: BotStateRef<'a>,
+    bot: Arc<BotState>,
+    sender: MessageSender,
 }
 
 impl BotState {
-    pub(super) const fn as_ready_context<'a>(
-        &'a self,
-        inner: &'a Ready,
+    pub(super) const fn into_ready_context(
+        self: Arc<Self>,
+        inner: Box<Ready>,
         shard_id: ShardId,
+        sender: MessageSender,
     ) -> ReadyContext {
         ReadyContext {
             inner,
             shard_id,
             bot: self,
+            sender,
+        }
+    }
+}
+
+impl ReadyContext {
+    async fn rejoin_twenty_four_seven(&self) -> Result<(), sqlx::Error> {
+        // `Ready` also fires after re-identifying, so guilds that are still connected are left alone
+        let lavalink = self.bot.lavalink();
+        let guild_ids = self
+            .inner
+            .guilds
+            .iter()
+            .map(|g| g.id)
+            .filter(|&id| {
+                lavalink.get_connection(id).is_none() && lavalink.get_player_data(id).is_none()
+            })
+            .map(|id| id.get() as i64)
+            .collect::<Vec<_>>();
+        if guild_ids.is_empty() {
+            return Ok(());
+        }
+        let twenty_four_seven_guilds = sqlx::query!(
+            r"--sql
+            SELECT id FROM guild_configs WHERE twenty_four_seven AND id = ANY($1);",
+            &guild_ids
+        )
+        .fetch_all(self.bot.db())
+        .await?;
+
+        for guild in twenty_four_seven_guilds {
+            traced::tokio_spawn(connection::rejoin_twenty_four_seven(
+                self.bot.clone(),
+                self.sender.clone(),
+                Id::new(guild.id as u64),
+            ));
         }
+        Ok(())
     }
 }
 
-impl Process for ReadyContext<'_> {
+impl Process for ReadyContext {
     async fn process(self) -> ProcessResult {
         let guild_count = self.inner.guilds.len();
         tracing::info!("running in {guild_count} guild(s)");
@@ -62,6 +105,7 @@ impl Process for ReadyContext<'_> {
         });
 
         while set.join_next().await.is_some() {}
+        self.rejoin_twenty_four_seven().await?;
         Ok(())
     }
 }
@@ -1,15 +1,25 @@
+mod fade;
+mod filter_revert;
 mod model;
 mod plugin;
 mod process;
 mod ready;
 mod track;
+mod voice;
 
 pub use self::{
+    fade::{fade_out, FadeSettings},
+    filter_revert::FilterRevert,
     model::{
-        wait_for_with, ClientAware as LavalinkAware, CorrectPlaylistInfo, CorrectTrackInfo,
-        DelegateMethods, Event, EventRecvResult, ExpectedPlayerAware, ExpectedPlayerDataAware,
-        IndexerType, Lavalink, Pitch, PlayerAware, PlayerDataAware, Queue, QueueItem, RepeatMode,
+        wait_for_with, AppliedFilterPreset, ChannelStatus, ClientAware as LavalinkAware,
+        ClientData, CorrectPlaylistInfo, CorrectTrackInfo, DelegateMethods, Event, EventRecvResult,
+        ExpectedPlayerAware, ExpectedPlayerDataAware, IndexerType, Lavalink, Pitch, PlayerAware,
+        PlayerDataAware, PlayerDefaults, Queue, QueueItem, RepeatMode,
+    },
+    plugin::{
+        LoadSearch, PlaylistType, PluginInfo, PluginPlaylistInfo, PluginTrackInfo, PreviewAware,
+        SearchResult, SearchType,
     },
-    plugin::PluginInfo,
     process::handlers,
+    track::refresh_now_playing_message,
 };
@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use lavalink_rs::{error::LavalinkResult, model::track::TrackData, player_context::PlayerContext};
+use sqlx::{Pool, Postgres};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::bot::core::r#const::misc::{FADE_POSITION_POLL_INTERVAL, FADE_STEP_INTERVAL};
+
+use super::model::PlayerDataRwLockArc;
+
+#[derive(Clone, Copy)]
+pub struct FadeSettings {
+    fade_in: Duration,
+    fade_out: Duration,
+}
+
+impl FadeSettings {
+    pub async fn fetch(
+        db: &Pool<Postgres>,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Self, sqlx::Error> {
+        let settings = sqlx::query!(
+            r"--sql
+            SELECT fade_in_secs, fade_out_secs FROM guild_configs WHERE id = $1;",
+            guild_id.get() as i64
+        )
+        .fetch_one(db)
+        .await?;
+
+        Ok(Self {
+            fade_in: Duration::from_secs(settings.fade_in_secs as u64),
+            fade_out: Duration::from_secs(settings.fade_out_secs as u64),
+        })
+    }
+
+    pub const fn fade_out(self) -> Duration {
+        self.fade_out
+    }
+
+    const fn is_disabled(self) -> bool {
+        self.fade_in.is_zero() && self.fade_out.is_zero()
+    }
+}
+
+/// Sets only the player volume, as a fraction of the player's regular volume. The regular volume is read from the player data on every step, so that volume changes mid-fade are followed.
+async fn set_volume(
+    player: &PlayerContext,
+    data: &PlayerDataRwLockArc,
+    fraction: f64,
+) -> LavalinkResult<()> {
+    let volume = f64::from(data.read().await.volume().get()) * fraction;
+    player.set_volume(volume.round() as u16).await?;
+    Ok(())
+}
+
+/// Ramps the player volume linearly, where `1.0` is the player's regular volume
+async fn ramp(
+    player: &PlayerContext,
+    data: &PlayerDataRwLockArc,
+    from: f64,
+    to: f64,
+    duration: Duration,
+) -> LavalinkResult<()> {
+    let steps = (duration.as_millis() / FADE_STEP_INTERVAL.as_millis()).max(1) as u32;
+
+    for step in 1..=steps {
+        let fraction = (to - from).mul_add(f64::from(step) / f64::from(steps), from);
+        set_volume(player, data, fraction).await?;
+        tokio::time::sleep(*FADE_STEP_INTERVAL).await;
+    }
+    Ok(())
+}
+
+/// Fades the currently playing track out, leaving the player volume muted
+pub async fn fade_out(
+    player: &PlayerContext,
+    data: &PlayerDataRwLockArc,
+    duration: Duration,
+) -> LavalinkResult<()> {
+    let state = player.get_player().await?;
+    if state.track.is_none() || state.paused {
+        return Ok(());
+    }
+
+    let volume = f64::from(data.read().await.volume().get());
+    let from = (f64::from(state.volume) / volume).min(1.);
+    ramp(player, data, from, 0., duration).await
+}
+
+async fn fade_in(
+    player: &PlayerContext,
+    data: &PlayerDataRwLockArc,
+    duration: Duration,
+) -> LavalinkResult<()> {
+    if !duration.is_zero() {
+        ramp(player, data, 0., 1., duration).await?;
+    }
+
+    let volume = data.read().await.volume().get();
+    if player.get_player().await?.volume != volume {
+        player.set_volume(volume).await?;
+    }
+    Ok(())
+}
+
+/// Waits until the track is `duration` away from ending, going by the player's reported position so that seeks and pauses are accounted for
+async fn wait_for_fade_out(
+    player: &PlayerContext,
+    length: u64,
+    duration: Duration,
+) -> LavalinkResult<Option<Duration>> {
+    let duration_ms = duration.as_millis() as u64;
+    loop {
+        let state = player.get_player().await?;
+        if state.track.is_none() {
+            return Ok(None);
+        }
+
+        let remaining = length.saturating_sub(state.state.position);
+        if !state.paused && remaining <= duration_ms {
+            return Ok(Some(Duration::from_millis(remaining)));
+        }
+
+        let until_fade_out = Duration::from_millis(remaining.saturating_sub(duration_ms));
+        tokio::time::sleep(until_fade_out.min(*FADE_POSITION_POLL_INTERVAL)).await;
+    }
+}
+
+async fn fade_track(
+    player: PlayerContext,
+    data: PlayerDataRwLockArc,
+    track: TrackData,
+    settings: FadeSettings,
+) -> LavalinkResult<()> {
+    fade_in(&player, &data, settings.fade_in).await?;
+
+    if settings.fade_out.is_zero() || track.info.is_stream {
+        return Ok(());
+    }
+    if let Some(remaining) =
+        wait_for_fade_out(&player, track.info.length, settings.fade_out).await?
+    {
+        fade_out(&player, &data, remaining).await?;
+    }
+    Ok(())
+}
+
+/// Fades the newly started track in, then schedules it to fade out just before it ends
+pub(super) async fn start(
+    player: PlayerContext,
+    data: &PlayerDataRwLockArc,
+    track: TrackData,
+    settings: FadeSettings,
+) {
+    if settings.is_disabled() {
+        if data.write().await.abort_fade() {
+            if let Err(error) = fade_in(&player, data, Duration::ZERO).await {
+                tracing::warn!(guild_id = ?player.guild_id, ?error, "restoring faded volume failed");
+            }
+        }
+        return;
+    }
+
+    let guild_id = player.guild_id;
+    let fade_data = data.clone();
+    let handle = tokio::spawn(async move {
+        if let Err(error) = fade_track(player, fade_data, track, settings).await {
+            tracing::warn!(?guild_id, ?error, "fading track failed");
+        }
+    });
+    data.write().await.set_fade(handle.abort_handle());
+}
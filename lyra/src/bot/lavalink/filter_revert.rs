@@ -0,0 +1,305 @@
+use std::time::Duration;
+
+use lavalink_rs::{error::LavalinkResult, model::player::Filters, player_context::PlayerContext};
+use serde_json::{Map, Value};
+use tokio::task::AbortHandle;
+
+use super::model::{AppliedFilterPreset, Pitch, PlayerData, PlayerDataRwLockArc};
+
+type FilterFields = Map<String, Value>;
+
+/// Tracked alongside the pitch in the player data
+const TIMESCALE_FIELD: &str = "timescale";
+
+fn fields_of(filters: &Filters) -> FilterFields {
+    match serde_json::to_value(filters).expect("filters must be serialisable") {
+        Value::Object(fields) => fields,
+        _ => unreachable!("filters are serialised as objects"),
+    }
+}
+
+fn filters_of(fields: FilterFields) -> Filters {
+    serde_json::from_value(Value::Object(fields)).expect("filter fields must be deserialisable")
+}
+
+fn field<'a>(fields: &'a FilterFields, key: &str) -> &'a Value {
+    fields.get(key).unwrap_or(&Value::Null)
+}
+
+/// A timed tuning change yet to be reverted, remembering what it replaced so that only what it changed is reverted
+pub(super) struct PendingFilterRevert {
+    id: u64,
+    previous: FilterFields,
+    applied: FilterFields,
+    previous_pitch: Pitch,
+    previous_filter_preset: Option<AppliedFilterPreset>,
+    applied_filter_preset: Option<&'static str>,
+    handle: AbortHandle,
+}
+
+impl PendingFilterRevert {
+    pub(super) fn abort(&self) {
+        self.handle.abort();
+    }
+
+    /// Reverts the fields this change made that are still in effect. Fields since changed by later timed changes are instead handed over to them, so that they revert to what was there before this change.
+    fn unwind(self, current: &mut FilterFields, later: &mut [Self], data: &mut PlayerData) {
+        let keys = self
+            .previous
+            .keys()
+            .chain(self.applied.keys())
+            .cloned()
+            .collect::<std::collections::BTreeSet<_>>();
+
+        for key in keys {
+            let previous = field(&self.previous, &key);
+            let applied = field(&self.applied, &key);
+            if previous == applied {
+                continue;
+            }
+
+            if field(current, &key) == applied {
+                if key == TIMESCALE_FIELD {
+                    *data.pitch_mut() = self.previous_pitch.clone();
+                }
+                current.insert(key, previous.clone());
+            } else if let Some(next) = later
+                .iter_mut()
+                .find(|r| field(&r.previous, &key) == applied)
+            {
+                if key == TIMESCALE_FIELD {
+                    next.previous_pitch = self.previous_pitch.clone();
+                }
+                next.previous.insert(key, previous.clone());
+            }
+        }
+
+        let previous_filter_preset = self
+            .previous_filter_preset
+            .as_ref()
+            .map(AppliedFilterPreset::name);
+        if previous_filter_preset == self.applied_filter_preset {
+            return;
+        }
+        if data.filter_preset().map(AppliedFilterPreset::name) == self.applied_filter_preset {
+            match self.previous_filter_preset {
+                Some(preset) => data.set_filter_preset(preset),
+                None => {
+                    data.take_filter_preset();
+                }
+            }
+        } else if let Some(next) = later.iter_mut().find(|r| {
+            r.previous_filter_preset
+                .as_ref()
+                .map(AppliedFilterPreset::name)
+                == self.applied_filter_preset
+        }) {
+            next.previous_filter_preset = self.previous_filter_preset;
+        }
+    }
+}
+
+/// The player's tuning just before a timed change, to be reverted to after the change is made
+pub struct FilterRevert {
+    player: PlayerContext,
+    data: PlayerDataRwLockArc,
+    after: Duration,
+    previous: Filters,
+    previous_pitch: Pitch,
+    previous_filter_preset: Option<AppliedFilterPreset>,
+}
+
+impl FilterRevert {
+    pub async fn snapshot(
+        player: PlayerContext,
+        data: PlayerDataRwLockArc,
+        after: Duration,
+    ) -> LavalinkResult<Self> {
+        let previous = player.get_player().await?.filters.unwrap_or_default();
+        let data_r = data.read().await;
+        let previous_pitch = data_r.pitch().clone();
+        let previous_filter_preset = data_r.filter_preset().cloned();
+        drop(data_r);
+
+        Ok(Self {
+            player,
+            data,
+            after,
+            previous,
+            previous_pitch,
+            previous_filter_preset,
+        })
+    }
+
+    pub const fn after(&self) -> Duration {
+        self.after
+    }
+
+    /// Schedules reverting the change made since the snapshot, after the given duration or when the current track ends, whichever comes first
+    pub async fn schedule(self) -> LavalinkResult<()> {
+        let applied = self.player.get_player().await?.filters.unwrap_or_default();
+        let mut data_w = self.data.write().await;
+        let id = data_w.next_filter_revert_id();
+
+        let (player, data, after) = (self.player.clone(), self.data.clone(), self.after);
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(after).await;
+            if let Err(error) = revert(&player, &data, id).await {
+                tracing::warn!(guild_id = ?player.guild_id, ?error, "reverting timed filter failed");
+            }
+        });
+
+        let applied_filter_preset = data_w.filter_preset().map(AppliedFilterPreset::name);
+        data_w.push_filter_revert(PendingFilterRevert {
+            id,
+            previous: fields_of(&self.previous),
+            applied: fields_of(&applied),
+            previous_pitch: self.previous_pitch,
+            previous_filter_preset: self.previous_filter_preset,
+            applied_filter_preset,
+            handle: handle.abort_handle(),
+        });
+        Ok(())
+    }
+}
+
+async fn revert(player: &PlayerContext, data: &PlayerDataRwLockArc, id: u64) -> LavalinkResult<()> {
+    let mut data_w = data.write().await;
+    let mut current = fields_of(&player.get_player().await?.filters.unwrap_or_default());
+    let mut reverts = data_w.take_filter_reverts();
+    let Some(index) = reverts.iter().position(|r| r.id == id) else {
+        data_w.restore_filter_reverts(reverts);
+        return Ok(());
+    };
+
+    let pending = reverts.remove(index);
+    pending.unwind(&mut current, &mut reverts[index..], &mut data_w);
+    data_w.restore_filter_reverts(reverts);
+
+    player.set_filters(filters_of(current)).await
+}
+
+/// Reverts every timed change, newest first, as the track they were made for has ended
+pub(super) async fn revert_all(
+    player: &PlayerContext,
+    data: &PlayerDataRwLockArc,
+) -> LavalinkResult<()> {
+    let mut data_w = data.write().await;
+    let reverts = data_w.take_filter_reverts();
+    if reverts.is_empty() {
+        return Ok(());
+    }
+    reverts.iter().for_each(PendingFilterRevert::abort);
+
+    let mut current = fields_of(&player.get_player().await?.filters.unwrap_or_default());
+    for pending in reverts.into_iter().rev() {
+        pending.unwind(&mut current, &mut [], &mut data_w);
+    }
+
+    player.set_filters(filters_of(current)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU16;
+
+    use lavalink_rs::model::player::{Equalizer, Filters, Timescale};
+
+    use super::{fields_of, filters_of, PendingFilterRevert};
+    use crate::bot::lavalink::model::{AppliedFilterPreset, Pitch, PlayerData};
+
+    fn equaliser(gain: f64) -> Filters {
+        Filters {
+            equalizer: Some(vec![Equalizer { band: 0, gain }]),
+            ..Filters::default()
+        }
+    }
+
+    fn timescale(pitch: f64) -> Filters {
+        Filters {
+            timescale: Some(Timescale {
+                pitch: Some(pitch),
+                ..Timescale::default()
+            }),
+            ..Filters::default()
+        }
+    }
+
+    fn player_data() -> PlayerData {
+        PlayerData::new(NonZeroU16::new(100).expect("volume is non-zero"), None)
+    }
+
+    fn pending(previous: &Filters, applied: &Filters) -> PendingFilterRevert {
+        PendingFilterRevert {
+            id: 0,
+            previous: fields_of(previous),
+            applied: fields_of(applied),
+            previous_pitch: Pitch::default(),
+            previous_filter_preset: None,
+            applied_filter_preset: None,
+            handle: tokio::spawn(async {}).abort_handle(),
+        }
+    }
+
+    #[tokio::test]
+    async fn unwind_reverts_fields_still_in_effect() {
+        let mut data = player_data();
+        let mut current = fields_of(&equaliser(0.5));
+
+        pending(&Filters::default(), &equaliser(0.5)).unwind(&mut current, &mut [], &mut data);
+        assert_eq!(filters_of(current), Filters::default());
+    }
+
+    #[tokio::test]
+    async fn unwind_keeps_fields_changed_since() {
+        let mut data = player_data();
+        let mut current = fields_of(&equaliser(0.25));
+
+        pending(&Filters::default(), &equaliser(0.5)).unwind(&mut current, &mut [], &mut data);
+        assert_eq!(filters_of(current), equaliser(0.25));
+    }
+
+    #[tokio::test]
+    async fn unwind_hands_fields_over_to_later_changes() {
+        let mut data = player_data();
+        let mut current = fields_of(&equaliser(1.));
+        let mut later = [pending(&equaliser(0.5), &equaliser(1.))];
+
+        pending(&Filters::default(), &equaliser(0.5)).unwind(&mut current, &mut later, &mut data);
+        assert_eq!(filters_of(current.clone()), equaliser(1.));
+
+        let [later] = later;
+        later.unwind(&mut current, &mut [], &mut data);
+        assert_eq!(filters_of(current), Filters::default());
+    }
+
+    #[tokio::test]
+    async fn unwind_restores_pitch_with_timescale() {
+        let mut data = player_data();
+        data.pitch_mut().set(1.5);
+        let mut current = fields_of(&timescale(1.5));
+
+        pending(&Filters::default(), &timescale(1.5)).unwind(&mut current, &mut [], &mut data);
+        assert_eq!(filters_of(current), Filters::default());
+        assert!((data.pitch().get() - 1.).abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn unwind_reverts_filter_preset() {
+        let mut data = player_data();
+        data.set_filter_preset(AppliedFilterPreset::new(
+            "nightcore",
+            timescale(1.2),
+            Filters::default(),
+            Pitch::default(),
+        ));
+        let mut current = fields_of(&timescale(1.2));
+
+        let revert = PendingFilterRevert {
+            applied_filter_preset: Some("nightcore"),
+            ..pending(&Filters::default(), &timescale(1.2))
+        };
+        revert.unwind(&mut current, &mut [], &mut data);
+        assert!(data.filter_preset().is_none());
+    }
+}
@@ -1,37 +1,51 @@
 mod connection;
 mod correct_info;
+mod filter_preset;
 mod pitch;
 mod queue;
 mod queue_indexer;
 
-use std::{num::NonZeroU16, ops::Deref, sync::Arc};
+use std::{
+    num::NonZeroU16,
+    ops::Deref,
+    sync::{Arc, OnceLock, Weak},
+};
 
 use lavalink_rs::{
-    client::LavalinkClient, error::LavalinkResult, model::player::ConnectionInfo,
+    client::LavalinkClient,
+    error::LavalinkResult,
+    model::player::{ConnectionInfo, Filters},
     player_context::PlayerContext,
 };
-use tokio::sync::RwLock;
+use tokio::{sync::RwLock, task::AbortHandle};
+use twilight_gateway::MessageSender;
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker, MessageMarker},
     Id,
 };
 
 use crate::bot::{
-    core::r#const,
+    core::{
+        model::{BotState, OwnedBotState},
+        r#const,
+    },
     gateway::{ExpectedGuildIdAware, GuildIdAware},
 };
 
 use self::connection::{Connection, ConnectionRef, ConnectionRefMut};
 
+use super::filter_revert::PendingFilterRevert;
+
 pub use self::{
-    connection::{wait_for_with, Event, EventRecvResult},
+    connection::{wait_for_with, ChannelStatus, Event, EventRecvResult},
     correct_info::{CorrectPlaylistInfo, CorrectTrackInfo},
+    filter_preset::AppliedFilterPreset,
     pitch::Pitch,
     queue::{Item as QueueItem, Queue, RepeatMode},
     queue_indexer::IndexerType,
 };
 
-type PlayerDataRwLockArc = Arc<RwLock<PlayerData>>;
+pub type PlayerDataRwLockArc = Arc<RwLock<PlayerData>>;
 
 pub trait ClientAware {
     fn lavalink(&self) -> &Lavalink;
@@ -64,16 +78,58 @@ pub struct PlayerData {
     queue: Queue,
     volume: NonZeroU16,
     pitch: Pitch,
+    filter_preset: Option<AppliedFilterPreset>,
+    fade: Option<AbortHandle>,
+    queue_end_timeout: Option<AbortHandle>,
+    paused_when_alone: bool,
+    paused_when_muted: bool,
+    filter_reverts: Vec<PendingFilterRevert>,
+    next_filter_revert_id: u64,
     now_playing_message_id: Option<Id<MessageMarker>>,
+    now_playing_message_channel_id: Option<Id<ChannelMarker>>,
+}
+
+/// What a guild's new players start with, as configured in `guild_configs`
+pub struct PlayerDefaults {
+    volume: NonZeroU16,
+    filters: Option<Filters>,
+    filter_preset: Option<AppliedFilterPreset>,
+}
+
+impl PlayerDefaults {
+    pub const fn new(
+        volume: NonZeroU16,
+        filters: Option<Filters>,
+        filter_preset: Option<AppliedFilterPreset>,
+    ) -> Self {
+        Self {
+            volume,
+            filters,
+            filter_preset,
+        }
+    }
 }
 
 impl PlayerData {
-    pub fn new() -> Self {
+    pub(super) fn new(volume: NonZeroU16, filter_preset: Option<AppliedFilterPreset>) -> Self {
+        let mut pitch = Pitch::new();
+        if let Some(preset_pitch) = filter_preset.as_ref().and_then(AppliedFilterPreset::pitch) {
+            pitch.set(preset_pitch);
+        }
+
         Self {
-            volume: NonZeroU16::new(100).expect("volume is non-zero"),
-            pitch: Pitch::new(),
+            volume,
+            pitch,
+            filter_preset,
+            fade: None,
+            queue_end_timeout: None,
+            paused_when_alone: false,
+            paused_when_muted: false,
+            filter_reverts: Vec::new(),
+            next_filter_revert_id: 0,
             queue: Queue::new(),
             now_playing_message_id: None,
+            now_playing_message_channel_id: None,
         }
     }
 
@@ -93,9 +149,140 @@ impl PlayerData {
         self.volume = volume;
     }
 
+    pub const fn pitch(&self) -> &Pitch {
+        &self.pitch
+    }
+
     pub fn pitch_mut(&mut self) -> &mut Pitch {
         &mut self.pitch
     }
+
+    pub const fn filter_preset(&self) -> Option<&AppliedFilterPreset> {
+        self.filter_preset.as_ref()
+    }
+
+    pub fn set_filter_preset(&mut self, filter_preset: AppliedFilterPreset) {
+        self.filter_preset = Some(filter_preset);
+    }
+
+    pub fn take_filter_preset(&mut self) -> Option<AppliedFilterPreset> {
+        self.filter_preset.take()
+    }
+
+    pub fn set_fade(&mut self, fade: AbortHandle) {
+        self.abort_fade();
+        self.fade = Some(fade);
+    }
+
+    /// Aborts the ongoing fade, returning whether there was one
+    pub fn abort_fade(&mut self) -> bool {
+        self.fade.take().map(|f| f.abort()).is_some()
+    }
+
+    pub fn set_queue_end_timeout(&mut self, timeout: AbortHandle) {
+        self.abort_queue_end_timeout();
+        self.queue_end_timeout = Some(timeout);
+    }
+
+    /// Forgets the queue end timeout without aborting it, as it is the one leaving
+    pub fn forget_queue_end_timeout(&mut self) {
+        self.queue_end_timeout = None;
+    }
+
+    pub fn abort_queue_end_timeout(&mut self) {
+        if let Some(timeout) = self.queue_end_timeout.take() {
+            timeout.abort();
+        }
+    }
+
+    pub fn set_paused_when_alone(&mut self) {
+        self.paused_when_alone = true;
+    }
+
+    /// Returns whether the player was paused as everyone left, resetting it
+    pub fn take_paused_when_alone(&mut self) -> bool {
+        std::mem::take(&mut self.paused_when_alone)
+    }
+
+    pub const fn paused_when_muted(&self) -> bool {
+        self.paused_when_muted
+    }
+
+    pub fn set_paused_when_muted(&mut self) {
+        self.paused_when_muted = true;
+    }
+
+    /// Returns whether the player was paused as the bot was server muted, resetting it
+    pub fn take_paused_when_muted(&mut self) -> bool {
+        std::mem::take(&mut self.paused_when_muted)
+    }
+
+    pub(super) fn next_filter_revert_id(&mut self) -> u64 {
+        self.next_filter_revert_id += 1;
+        self.next_filter_revert_id
+    }
+
+    pub(super) fn push_filter_revert(&mut self, revert: PendingFilterRevert) {
+        self.filter_reverts.push(revert);
+    }
+
+    pub(super) fn take_filter_reverts(&mut self) -> Vec<PendingFilterRevert> {
+        std::mem::take(&mut self.filter_reverts)
+    }
+
+    pub(super) fn restore_filter_reverts(&mut self, reverts: Vec<PendingFilterRevert>) {
+        self.filter_reverts = reverts;
+    }
+
+    /// Aborts every scheduled timed filter revert, leaving the filters as they are
+    pub fn abort_filter_reverts(&mut self) {
+        self.take_filter_reverts()
+            .iter()
+            .for_each(PendingFilterRevert::abort);
+    }
+
+    pub fn set_now_playing_message(
+        &mut self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) {
+        self.now_playing_message_channel_id = Some(channel_id);
+        self.now_playing_message_id = Some(message_id);
+    }
+
+    pub const fn now_playing_message(&self) -> Option<(Id<ChannelMarker>, Id<MessageMarker>)> {
+        match (
+            self.now_playing_message_channel_id,
+            self.now_playing_message_id,
+        ) {
+            (Some(channel_id), Some(message_id)) => Some((channel_id, message_id)),
+            _ => None,
+        }
+    }
+
+    pub fn take_now_playing_message(&mut self) -> Option<(Id<ChannelMarker>, Id<MessageMarker>)> {
+        Some((
+            self.now_playing_message_channel_id.take()?,
+            self.now_playing_message_id.take()?,
+        ))
+    }
+}
+
+/// Data attached to the lavalink client, allowing event hooks to access the bot's state
+pub struct ClientData(OnceLock<Weak<BotState>>);
+
+impl ClientData {
+    pub const fn new() -> Self {
+        Self(OnceLock::new())
+    }
+
+    pub fn set_bot(&self, bot: &OwnedBotState) {
+        let _ = self.0.set(Arc::downgrade(bot));
+    }
+
+    pub fn bot(&self) -> Option<OwnedBotState> {
+        self.0.get()?.upgrade()
+    }
 }
 
 pub struct Lavalink {
@@ -160,6 +347,7 @@ pub trait DelegateMethods {
     async fn new_player_data(
         &self,
         guild_id: impl Into<LavalinkGuildId> + Send + Copy,
+        defaults: PlayerDefaults,
     ) -> LavalinkResult<()> {
         let now = tokio::time::Instant::now();
         let info = self
@@ -170,10 +358,23 @@ pub trait DelegateMethods {
             .await?;
         tracing::trace!("getting lavalink connection info took {:?}", now.elapsed());
 
-        let data = Arc::new(RwLock::new(PlayerData::new()));
-        self._create_player_context_with_data(guild_id, info, data)
+        let PlayerDefaults {
+            volume,
+            filters,
+            filter_preset,
+        } = defaults;
+        let data = Arc::new(RwLock::new(PlayerData::new(volume, filter_preset)));
+        let player = self
+            ._create_player_context_with_data(guild_id, info, data)
             .await?;
 
+        if volume.get() != 100 {
+            player.set_volume(volume.get()).await?;
+        }
+        if let Some(filters) = filters {
+            player.set_filters(filters).await?;
+        }
+
         Ok(())
     }
 
@@ -204,9 +405,12 @@ impl Lavalink {
         guild_id: Id<GuildMarker>,
         channel_id: Id<ChannelMarker>,
         text_channel_id: Id<ChannelMarker>,
+        sender: MessageSender,
     ) {
-        self.connections
-            .insert(guild_id, Connection::new(channel_id, text_channel_id));
+        self.connections.insert(
+            guild_id,
+            Connection::new(channel_id, text_channel_id, sender),
+        );
     }
 
     pub fn drop_connection(&self, guild_id: Id<GuildMarker>) {
@@ -1,4 +1,5 @@
 use tokio::sync::{broadcast, Notify};
+use twilight_gateway::MessageSender;
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker, UserMarker},
     Id,
@@ -11,6 +12,8 @@ pub struct Connection {
     pub channel_id: Id<ChannelMarker>,
     pub text_channel_id: Id<ChannelMarker>,
     pub mute: bool,
+    sender: MessageSender,
+    channel_status: Option<ChannelStatus>,
     poll: Option<Poll>,
     change: Notify,
     event_sender: broadcast::Sender<Event>,
@@ -21,11 +24,17 @@ pub(super) type ConnectionRefMut<'a> =
     dashmap::mapref::one::RefMut<'a, Id<GuildMarker>, Connection>;
 
 impl Connection {
-    pub(super) fn new(channel_id: Id<ChannelMarker>, text_channel_id: Id<ChannelMarker>) -> Self {
+    pub(super) fn new(
+        channel_id: Id<ChannelMarker>,
+        text_channel_id: Id<ChannelMarker>,
+        sender: MessageSender,
+    ) -> Self {
         Self {
             channel_id,
             text_channel_id,
             mute: false,
+            sender,
+            channel_status: None,
             change: Notify::new(),
             event_sender: broadcast::channel(16).0,
             poll: None,
@@ -41,6 +50,24 @@ impl Connection {
         .is_ok()
     }
 
+    /// The gateway sender of the shard this connection was made from, for leaving outside of gateway events
+    pub const fn sender(&self) -> &MessageSender {
+        &self.sender
+    }
+
+    /// What the bot has changed about the voice channel's topic or status, if anything, so it can be restored
+    pub const fn channel_status(&self) -> Option<&ChannelStatus> {
+        self.channel_status.as_ref()
+    }
+
+    pub fn set_channel_status(&mut self, status: ChannelStatus) {
+        self.channel_status = Some(status);
+    }
+
+    pub fn take_channel_status(&mut self) -> Option<ChannelStatus> {
+        self.channel_status.take()
+    }
+
     pub const fn poll(&self) -> Option<&Poll> {
         self.poll.as_ref()
     }
@@ -67,6 +94,12 @@ impl Connection {
     }
 }
 
+#[derive(Debug)]
+pub enum ChannelStatus {
+    StageTopic { previous: Option<String> },
+    VoiceStatus { previous: Option<String> },
+}
+
 #[derive(Debug, Clone, const_panic::PanicFmt)]
 pub struct AlternateVoteCastUserId(u64);
 
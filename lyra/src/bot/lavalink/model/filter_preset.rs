@@ -0,0 +1,126 @@
+use lavalink_rs::model::player::{Filters, Timescale};
+
+use super::Pitch;
+
+/// A filter preset applied to a player, alongside the filters it replaced so that only what it changed can be reverted
+#[derive(Clone)]
+pub struct AppliedFilterPreset {
+    name: &'static str,
+    applied: Filters,
+    replaced: Filters,
+    replaced_pitch: Pitch,
+}
+
+fn overlay_timescale(top: Option<Timescale>, base: Option<Timescale>) -> Option<Timescale> {
+    match (top, base) {
+        (Some(top), Some(base)) => Some(Timescale {
+            speed: top.speed.or(base.speed),
+            pitch: top.pitch.or(base.pitch),
+            rate: top.rate.or(base.rate),
+        }),
+        (top, base) => top.or(base),
+    }
+}
+
+fn revert_timescale(
+    applied: Option<Timescale>,
+    replaced: Option<Timescale>,
+    current: Option<Timescale>,
+) -> Option<Timescale> {
+    let Some(applied) = applied else {
+        return current;
+    };
+    let replaced = replaced.unwrap_or_default();
+    let current = current.unwrap_or_default();
+
+    let revert = |applied: Option<f64>, replaced: Option<f64>, current: Option<f64>| {
+        if applied.is_some() {
+            replaced
+        } else {
+            current
+        }
+    };
+    let timescale = Timescale {
+        speed: revert(applied.speed, replaced.speed, current.speed),
+        pitch: revert(applied.pitch, replaced.pitch, current.pitch),
+        rate: revert(applied.rate, replaced.rate, current.rate),
+    };
+
+    (timescale.speed.is_some() || timescale.pitch.is_some() || timescale.rate.is_some())
+        .then_some(timescale)
+}
+
+fn revert<T>(applied: Option<T>, replaced: Option<T>, current: Option<T>) -> Option<T> {
+    if applied.is_some() {
+        replaced
+    } else {
+        current
+    }
+}
+
+impl AppliedFilterPreset {
+    pub const fn new(
+        name: &'static str,
+        applied: Filters,
+        replaced: Filters,
+        replaced_pitch: Pitch,
+    ) -> Self {
+        Self {
+            name,
+            applied,
+            replaced,
+            replaced_pitch,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The pitch multiplier this preset sets, if any
+    pub fn pitch(&self) -> Option<f64> {
+        self.applied.timescale.as_ref().and_then(|t| t.pitch)
+    }
+
+    /// Overlays the filters this preset changes on top of the given filters
+    pub fn apply_to(&self, filters: Filters) -> Filters {
+        let applied = self.applied.clone();
+
+        Filters {
+            equalizer: applied.equalizer.or(filters.equalizer),
+            karaoke: applied.karaoke.or(filters.karaoke),
+            timescale: overlay_timescale(applied.timescale, filters.timescale),
+            tremolo: applied.tremolo.or(filters.tremolo),
+            vibrato: applied.vibrato.or(filters.vibrato),
+            rotation: applied.rotation.or(filters.rotation),
+            distortion: applied.distortion.or(filters.distortion),
+            channel_mix: applied.channel_mix.or(filters.channel_mix),
+            low_pass: applied.low_pass.or(filters.low_pass),
+            ..filters
+        }
+    }
+
+    /// Reverts only the filters this preset changed back to what they were before, returning the reverted filters and the pitch to restore to if the preset changed it
+    pub fn revert(self, filters: Filters) -> (Filters, Option<Pitch>) {
+        let replaced_pitch = self.pitch().map(|_| self.replaced_pitch);
+        let (applied, replaced) = (self.applied, self.replaced);
+
+        let filters = Filters {
+            equalizer: revert(applied.equalizer, replaced.equalizer, filters.equalizer),
+            karaoke: revert(applied.karaoke, replaced.karaoke, filters.karaoke),
+            timescale: revert_timescale(applied.timescale, replaced.timescale, filters.timescale),
+            tremolo: revert(applied.tremolo, replaced.tremolo, filters.tremolo),
+            vibrato: revert(applied.vibrato, replaced.vibrato, filters.vibrato),
+            rotation: revert(applied.rotation, replaced.rotation, filters.rotation),
+            distortion: revert(applied.distortion, replaced.distortion, filters.distortion),
+            channel_mix: revert(
+                applied.channel_mix,
+                replaced.channel_mix,
+                filters.channel_mix,
+            ),
+            low_pass: revert(applied.low_pass, replaced.low_pass, filters.low_pass),
+            ..filters
+        };
+        (filters, replaced_pitch)
+    }
+}
@@ -8,6 +8,12 @@ pub struct Pitch {
     half_tone_shifts: i32,
 }
 
+impl Default for Pitch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Pitch {
     const DEFAULT_MULTIPLIER: f64 = 1.;
 
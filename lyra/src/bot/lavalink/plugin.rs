@@ -1,4 +1,5 @@
 mod lava_search;
 mod lava_src;
 
-pub use lava_src::PluginInfo;
+pub use lava_search::{LoadSearch, SearchResult, SearchType};
+pub use lava_src::{PlaylistType, PluginInfo, PluginPlaylistInfo, PluginTrackInfo, PreviewAware};
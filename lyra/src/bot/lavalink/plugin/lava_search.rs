@@ -1 +1,115 @@
+use itertools::Itertools;
+use lavalink_rs::{
+    client::LavalinkClient,
+    error::LavalinkResult,
+    model::{
+        track::{PlaylistData, TrackData},
+        GuildId,
+    },
+};
+use serde::Deserialize;
 
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    /// The tracks of the search result
+    #[serde(default)]
+    pub tracks: Vec<TrackData>,
+    /// The albums of the search result
+    #[serde(default)]
+    pub albums: Vec<PlaylistData>,
+    /// The artists of the search result
+    #[serde(default)]
+    pub artists: Vec<PlaylistData>,
+    /// The playlists of the search result
+    #[serde(default)]
+    pub playlists: Vec<PlaylistData>,
+    /// The text suggestions of the search result
+    #[serde(default)]
+    pub texts: Vec<Text>,
+}
+
+impl SearchResult {
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty()
+            && self.albums.is_empty()
+            && self.artists.is_empty()
+            && self.playlists.is_empty()
+            && self.texts.is_empty()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Text {
+    /// The text suggestion
+    pub text: String,
+}
+
+#[derive(Clone, Copy)]
+pub enum SearchType {
+    Track,
+    Album,
+    Artist,
+    Playlist,
+    Text,
+}
+
+impl SearchType {
+    pub const ALL: [Self; 5] = [
+        Self::Track,
+        Self::Album,
+        Self::Artist,
+        Self::Playlist,
+        Self::Text,
+    ];
+
+    const fn value(self) -> &'static str {
+        match self {
+            Self::Track => "track",
+            Self::Album => "album",
+            Self::Artist => "artist",
+            Self::Playlist => "playlist",
+            Self::Text => "text",
+        }
+    }
+}
+
+pub trait LoadSearch {
+    /// Searches via the `/v4/loadsearch` endpoint, returning `None` if nothing was found
+    async fn load_search(
+        &self,
+        guild_id: impl Into<GuildId> + Send,
+        query: &str,
+        types: &[SearchType],
+    ) -> LavalinkResult<Option<SearchResult>>;
+}
+
+impl LoadSearch for LavalinkClient {
+    async fn load_search(
+        &self,
+        guild_id: impl Into<GuildId> + Send,
+        query: &str,
+        types: &[SearchType],
+    ) -> LavalinkResult<Option<SearchResult>> {
+        let node = self.get_node_for_guild(guild_id).await;
+
+        let path = format!(
+            "/loadsearch?query={}&types={}",
+            urlencoding::encode(query),
+            types.iter().map(|t| t.value()).join(",")
+        );
+        let uri = node.http.path_to_uri(&path, true)?;
+
+        let raw = node
+            .http
+            .raw_request(http::Method::GET, uri, None::<&()>)
+            .await?;
+        if raw.is_empty() {
+            return Ok(None);
+        }
+
+        let result = serde_json::from_slice::<SearchResult>(&raw)?;
+        Ok((!result.is_empty()).then_some(result))
+    }
+}
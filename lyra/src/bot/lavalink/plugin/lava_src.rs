@@ -17,6 +17,28 @@ pub struct PluginTrackInfo {
     is_preview: bool,
 }
 
+impl PluginTrackInfo {
+    pub fn album_name(&self) -> Option<&str> {
+        self.album_name.as_deref()
+    }
+
+    pub fn album_art_url(&self) -> Option<&str> {
+        self.album_art_url.as_deref()
+    }
+
+    pub fn artist_url(&self) -> Option<&str> {
+        self.artist_url.as_deref()
+    }
+
+    pub fn artist_artwork_uri(&self) -> Option<&str> {
+        self.artist_artwork_uri.as_deref()
+    }
+
+    pub const fn is_preview(&self) -> bool {
+        self.is_preview
+    }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct PluginPlaylistInfo {
@@ -33,9 +55,31 @@ pub struct PluginPlaylistInfo {
     total_tracks: Option<usize>,
 }
 
-#[derive(Deserialize, Debug)]
+impl PluginPlaylistInfo {
+    pub const fn kind(&self) -> PlaylistType {
+        self.kind
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    pub fn artwork_url(&self) -> Option<&str> {
+        self.artwork_url.as_deref()
+    }
+
+    pub const fn total_tracks(&self) -> Option<usize> {
+        self.total_tracks
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
-enum PlaylistType {
+pub enum PlaylistType {
     /// The playlist is an album
     Album,
     /// The playlist is a playlist
@@ -46,6 +90,18 @@ enum PlaylistType {
     Recommendations,
 }
 
+impl std::fmt::Display for PlaylistType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Album => "album",
+            Self::Playlist => "playlist",
+            Self::Artist => "artist",
+            Self::Recommendations => "recommendations",
+        };
+        write!(f, "{s}")
+    }
+}
+
 pub trait PluginInfo {
     type Info;
     fn get_plugin_info(&self) -> Option<&serde_json::Value>;
@@ -61,6 +117,14 @@ pub trait PluginInfo {
     }
 }
 
+pub trait PreviewAware: PluginInfo<Info = PluginTrackInfo> {
+    fn is_preview(&self) -> bool {
+        self.parse_plugin_info().is_some_and(|i| i.is_preview())
+    }
+}
+
+impl PreviewAware for lavalink_rs::model::track::TrackData {}
+
 impl PluginInfo for lavalink_rs::model::track::TrackData {
     type Info = PluginTrackInfo;
 
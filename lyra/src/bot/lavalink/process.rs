@@ -7,6 +7,7 @@ pub fn handlers() -> Events {
         track_end: Some(super::track::end),
         track_exception: Some(super::track::exception),
         track_stuck: Some(super::track::stuck),
+        websocket_closed: Some(super::voice::websocket_closed),
         ..Default::default()
     }
 }
@@ -1,25 +1,296 @@
+use chrono::Duration;
 use lavalink_rs::{
     client::LavalinkClient,
     hook,
-    model::events::{TrackEnd, TrackException, TrackStart, TrackStuck},
+    model::{
+        events::{TrackEnd, TrackException, TrackStart, TrackStuck},
+        track::TrackData,
+        GuildId,
+    },
 };
+use twilight_mention::Mention;
+use twilight_model::{
+    channel::message::Embed,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_util::builder::embed::{EmbedAuthorBuilder, EmbedBuilder, ImageSource};
 
 use crate::bot::{
-    error::lavalink::ProcessResult,
-    lavalink::{model::CorrectTrackInfo, DelegateMethods},
+    component::{connection, queue},
+    core::{
+        model::{BotState, HttpAware},
+        r#const::{colours, exit_code::WARNING},
+    },
+    error::lavalink::{ProcessError, ProcessResult},
+    ext::util::PrettifiedTimestamp,
+    lavalink::{
+        fade::{self, FadeSettings},
+        filter_revert,
+        model::{ClientData, CorrectTrackInfo, PlayerDataRwLockArc, QueueItem},
+        DelegateMethods, LavalinkAware, PluginInfo, PluginTrackInfo,
+    },
 };
 
 // FIXME: don't debug `LavalinkClient` until `lavalink_rs` stops stack overflowing
 
+fn generate_now_playing_embed(
+    track: &TrackData,
+    requester: Option<Id<UserMarker>>,
+    paused_when_muted: bool,
+) -> Result<Embed, ProcessError> {
+    let track_info = &track.info;
+    let plugin_info = track.parse_plugin_info();
+
+    let mut author = EmbedAuthorBuilder::new(track_info.corrected_author().into_owned());
+    if let Some(artist_url) = plugin_info.as_ref().and_then(PluginTrackInfo::artist_url) {
+        author = author.url(artist_url);
+    }
+    if let Some(icon) = plugin_info
+        .as_ref()
+        .and_then(PluginTrackInfo::artist_artwork_uri)
+        .and_then(|uri| ImageSource::url(uri).ok())
+    {
+        author = author.icon_url(icon);
+    }
+
+    let mut description = Vec::new();
+    if let Some(album_name) = plugin_info.as_ref().and_then(PluginTrackInfo::album_name) {
+        description.push(format!("💿 `{album_name}`"));
+    }
+    description.push(format!(
+        "⌛ `{}`",
+        PrettifiedTimestamp::from(Duration::milliseconds(track_info.length as i64))
+    ));
+    if let Some(requester) = requester {
+        description.push(format!("🎤 {}", requester.mention()));
+    }
+    if plugin_info
+        .as_ref()
+        .is_some_and(PluginTrackInfo::is_preview)
+    {
+        description.push(format!(
+            "{WARNING} **This is only a 30-second preview.** The full track is not available from this source."
+        ));
+    }
+    if paused_when_muted {
+        description.push(String::from(
+            "🔇 **Server muted.** Playback is paused until the bot is unmuted.",
+        ));
+    }
+
+    let mut embed = EmbedBuilder::new()
+        .author(author)
+        .title(track_info.corrected_title().into_owned())
+        .description(description.join("\n"))
+        .color(colours::EMBED_DEFAULT);
+    if let Some(ref uri) = track_info.uri {
+        embed = embed.url(uri);
+    }
+    if let Some(thumbnail) = plugin_info
+        .as_ref()
+        .and_then(PluginTrackInfo::album_art_url)
+        .or(track_info.artwork_url.as_deref())
+        .and_then(|url| ImageSource::url(url).ok())
+    {
+        embed = embed.thumbnail(thumbnail);
+    }
+
+    Ok(embed.validate()?.build())
+}
+
+async fn send_now_playing_message(
+    lavalink: &LavalinkClient,
+    data: &PlayerDataRwLockArc,
+    event: &TrackStart,
+) -> ProcessResult {
+    let Some(bot) = lavalink.data::<ClientData>()?.bot() else {
+        return Ok(());
+    };
+    let guild_id = Id::<GuildMarker>::new(event.guild_id.0);
+    let Some(text_channel_id) = bot
+        .lavalink()
+        .get_connection(guild_id)
+        .map(|c| c.text_channel_id)
+    else {
+        return Ok(());
+    };
+
+    let now_playing = sqlx::query!(
+        r"--sql
+        SELECT now_playing FROM guild_configs WHERE id = $1;",
+        guild_id.get() as i64
+    )
+    .fetch_one(bot.db())
+    .await?
+    .now_playing;
+    if !now_playing {
+        return Ok(());
+    }
+
+    let data_r = data.read().await;
+    let requester = data_r.queue().current().map(QueueItem::requester);
+    let embed = generate_now_playing_embed(&event.track, requester, data_r.paused_when_muted())?;
+    drop(data_r);
+    let message = bot
+        .http()
+        .create_message(text_channel_id)
+        .embeds(&[embed])
+        .await?
+        .model()
+        .await?;
+
+    data.write()
+        .await
+        .set_now_playing_message(text_channel_id, message.id);
+    Ok(())
+}
+
+/// Edits the now-playing message of the current track, so that it shows whether it is paused as the bot is server muted
+pub async fn refresh_now_playing_message(
+    bot: &BotState,
+    guild_id: Id<GuildMarker>,
+) -> ProcessResult {
+    let Some(data) = bot.lavalink().get_player_data(guild_id) else {
+        return Ok(());
+    };
+    let data_r = data.read().await;
+    let (Some((channel_id, message_id)), Some(item)) =
+        (data_r.now_playing_message(), data_r.queue().current())
+    else {
+        return Ok(());
+    };
+    let embed = generate_now_playing_embed(
+        item.track(),
+        Some(item.requester()),
+        data_r.paused_when_muted(),
+    )?;
+    drop(data_r);
+
+    bot.http()
+        .update_message(channel_id, message_id)
+        .embeds(Some(&[embed]))
+        .await?;
+    Ok(())
+}
+
+async fn delete_now_playing_message(lavalink: &LavalinkClient, data: &PlayerDataRwLockArc) {
+    let Some((channel_id, message_id)) = data.write().await.take_now_playing_message() else {
+        return;
+    };
+    let Some(bot) = lavalink.data::<ClientData>().ok().and_then(|d| d.bot()) else {
+        return;
+    };
+
+    if let Err(error) = bot.http().delete_message(channel_id, message_id).await {
+        tracing::warn!(%error, "deleting now playing message failed");
+    }
+}
+
+async fn start_fades(
+    lavalink: &LavalinkClient,
+    data: &PlayerDataRwLockArc,
+    event: &TrackStart,
+) -> ProcessResult {
+    let Some(bot) = lavalink.data::<ClientData>()?.bot() else {
+        return Ok(());
+    };
+    let Some(player) = lavalink.get_player_context(event.guild_id) else {
+        return Ok(());
+    };
+
+    let guild_id = Id::<GuildMarker>::new(event.guild_id.0);
+    let settings = FadeSettings::fetch(bot.db(), guild_id).await?;
+    fade::start(player, data, event.track.clone(), settings).await;
+    Ok(())
+}
+
+async fn revert_timed_filters(
+    lavalink: &LavalinkClient,
+    data: &PlayerDataRwLockArc,
+    event: &TrackEnd,
+) {
+    let Some(player) = lavalink.get_player_context(event.guild_id) else {
+        return;
+    };
+
+    if let Err(error) = filter_revert::revert_all(&player, data).await {
+        tracing::warn!(?error, "reverting timed filters failed");
+    }
+}
+
+async fn save_twenty_four_seven_queue(
+    lavalink: &LavalinkClient,
+    data: &PlayerDataRwLockArc,
+    guild_id: GuildId,
+) -> ProcessResult {
+    let Some(bot) = lavalink.data::<ClientData>()?.bot() else {
+        return Ok(());
+    };
+
+    let guild_id = Id::<GuildMarker>::new(guild_id.0);
+    if let Err(error) = connection::save_twenty_four_seven_queue(bot.db(), guild_id, data).await {
+        tracing::warn!(?error, "saving 24/7 queue failed");
+    }
+    Ok(())
+}
+
+async fn update_channel_status(lavalink: &LavalinkClient, event: &TrackStart) -> ProcessResult {
+    let Some(bot) = lavalink.data::<ClientData>()?.bot() else {
+        return Ok(());
+    };
+
+    let guild_id = Id::<GuildMarker>::new(event.guild_id.0);
+    if let Err(error) = connection::update_channel_status(&bot, guild_id, &event.track).await {
+        tracing::warn!(?error, "updating channel status failed");
+    }
+    Ok(())
+}
+
+async fn update_song_request_message(
+    lavalink: &LavalinkClient,
+    guild_id: GuildId,
+) -> ProcessResult {
+    let Some(bot) = lavalink.data::<ClientData>()?.bot() else {
+        return Ok(());
+    };
+
+    let guild_id = Id::<GuildMarker>::new(guild_id.0);
+    if let Err(error) = queue::update_song_request_message(&bot, guild_id).await {
+        tracing::warn!(?error, "updating song request message failed");
+    }
+    Ok(())
+}
+
+async fn start_queue_end_timeout(lavalink: &LavalinkClient, event: &TrackEnd) -> ProcessResult {
+    let Some(bot) = lavalink.data::<ClientData>()?.bot() else {
+        return Ok(());
+    };
+
+    let guild_id = Id::<GuildMarker>::new(event.guild_id.0);
+    connection::start_queue_end_timeout(bot, guild_id).await?;
+    Ok(())
+}
+
 #[tracing::instrument(err, skip_all, name = "track_start")]
-async fn impl_start(event: &TrackStart) -> ProcessResult {
+async fn impl_start(lavalink: LavalinkClient, event: &TrackStart) -> ProcessResult {
     tracing::debug!(
         "guild {} started {:?}",
         event.guild_id.0,
         event.track.info.checked_title()
     );
 
-    Ok(())
+    let Some(data) = lavalink.get_player_data(event.guild_id) else {
+        return Ok(());
+    };
+    data.write().await.abort_queue_end_timeout();
+    save_twenty_four_seven_queue(&lavalink, &data, event.guild_id).await?;
+    update_channel_status(&lavalink, event).await?;
+    update_song_request_message(&lavalink, event.guild_id).await?;
+    start_fades(&lavalink, &data, event).await?;
+    send_now_playing_message(&lavalink, &data, event).await
 }
 
 #[tracing::instrument(err, skip_all, name = "track_end")]
@@ -34,12 +305,14 @@ async fn impl_end(lavalink: LavalinkClient, event: &TrackEnd) -> ProcessResult {
     let Some(data) = lavalink.get_player_data(guild_id) else {
         tracing::trace!(?guild_id, "track ended via forced disconnection");
 
-        return Ok(());
+        return update_song_request_message(&lavalink, guild_id).await;
     };
+    delete_now_playing_message(&lavalink, &data).await;
+    revert_timed_filters(&lavalink, &data, event).await;
+
     let data_r = data.read().await;
     let queue = data_r.queue();
 
-    // TODO: handle now playing message deleting
     if queue.advance_locked() {
         queue.advance_unlock();
     } else {
@@ -49,9 +322,13 @@ async fn impl_end(lavalink: LavalinkClient, event: &TrackEnd) -> ProcessResult {
         let queue = data_w.queue_mut();
 
         queue.advance();
-        if let Some(item) = queue.current() {
-            lavalink.player(guild_id).play_now(item.track()).await?;
-        }
+        let Some(item) = queue.current() else {
+            drop(data_w);
+            save_twenty_four_seven_queue(&lavalink, &data, guild_id).await?;
+            update_song_request_message(&lavalink, guild_id).await?;
+            return start_queue_end_timeout(&lavalink, event).await;
+        };
+        lavalink.player(guild_id).play_now(item.track()).await?;
     }
 
     Ok(())
@@ -68,8 +345,8 @@ async fn impl_stuck() -> ProcessResult {
 }
 
 #[hook]
-pub(super) async fn start(_: LavalinkClient, _session_id: String, event: &TrackStart) {
-    let _ = impl_start(event).await;
+pub(super) async fn start(lavalink: LavalinkClient, _session_id: String, event: &TrackStart) {
+    let _ = impl_start(lavalink, event).await;
 }
 
 #[hook]
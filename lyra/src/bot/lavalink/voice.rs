@@ -0,0 +1,31 @@
+use lavalink_rs::{client::LavalinkClient, hook, model::events::WebSocketClosed};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::bot::{component::connection, error::lavalink::ProcessResult, lavalink::ClientData};
+
+#[tracing::instrument(err, skip_all, name = "websocket_closed")]
+async fn impl_websocket_closed(lavalink: LavalinkClient, event: &WebSocketClosed) -> ProcessResult {
+    tracing::debug!(
+        "guild {} voice websocket closed with code {}: {}",
+        event.guild_id.0,
+        event.code,
+        event.reason
+    );
+
+    let Some(bot) = lavalink.data::<ClientData>()?.bot() else {
+        return Ok(());
+    };
+
+    let guild_id = Id::<GuildMarker>::new(event.guild_id.0);
+    connection::rejoin_after_network_drop(bot, guild_id, event.code).await?;
+    Ok(())
+}
+
+#[hook]
+pub(super) async fn websocket_closed(
+    lavalink: LavalinkClient,
+    _session_id: String,
+    event: &WebSocketClosed,
+) {
+    let _ = impl_websocket_closed(lavalink, event).await;
+}
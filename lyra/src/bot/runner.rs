@@ -38,7 +38,7 @@ use super::{
     },
     error::runner::{StartError, WaitForSignalError, WaitUntilShutdownError},
     gateway,
-    lavalink::{self, DelegateMethods, LavalinkAware},
+    lavalink::{self, ClientData, DelegateMethods, LavalinkAware},
 };
 use super::{gateway::LastCachedStates, lavalink::Lavalink};
 
@@ -48,7 +48,10 @@ const CONFIG: Config = Config {
     lavalink_pwd: dotenv!("LAVALINK_SERVER_PASSWORD"),
     database_url: dotenv!("DATABASE_URL"),
 };
-const INTENTS: Intents = Intents::GUILDS.union(Intents::GUILD_VOICE_STATES);
+const INTENTS: Intents = Intents::GUILDS
+    .union(Intents::GUILD_VOICE_STATES)
+    .union(Intents::GUILD_MESSAGES)
+    .union(Intents::MESSAGE_CONTENT);
 
 static SHUTDOWN: AtomicBool = AtomicBool::new(false);
 
@@ -97,6 +100,10 @@ pub(super) async fn start() -> Result<(), StartError> {
     let mut senders = Vec::with_capacity(shards_len);
     let mut tasks = Vec::with_capacity(shards_len);
     let bot = Arc::new(BotState::new(db, http, lavalink));
+    bot.lavalink()
+        .data::<ClientData>()
+        .expect("data type is valid")
+        .set_bot(&bot);
     bot.interaction().await?.register_global_commands().await?;
 
     for shard in shards {
@@ -129,7 +136,13 @@ async fn build_lavalink_client(user_id: Id<UserMarker>) -> Lavalink {
         ..Default::default()
     }]);
 
-    let client = LavalinkClient::new(events, nodes, NodeDistributionStrategy::new()).await;
+    let client = LavalinkClient::new_with_data(
+        events,
+        nodes,
+        NodeDistributionStrategy::new(),
+        Arc::new(ClientData::new()),
+    )
+    .await;
     client.into()
 }
 
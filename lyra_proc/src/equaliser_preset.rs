@@ -20,7 +20,12 @@ pub fn impl_read_equaliser_presets_as(ty: &Ident) -> TokenStream {
     let presets_toml = toml::from_str::<Presets>(presets_str)
         .unwrap_or_else(|e| panic!("parsing equalisers.toml failed: {e:?}"));
 
-    let preset_names_strs = presets_toml.equaliser.iter().map(|e| e.name.as_str());
+    let preset_names_strs = presets_toml
+        .equaliser
+        .iter()
+        .map(|e| e.name.as_str())
+        .collect::<Box<[_]>>();
+    let presets_len = preset_names_strs.len();
     let preset_names_idents = presets_toml
         .equaliser
         .iter()
@@ -30,7 +35,7 @@ pub fn impl_read_equaliser_presets_as(ty: &Ident) -> TokenStream {
                 proc_macro::Span::call_site().into(),
             )
         })
-        .collect::<Box<_>>();
+        .collect::<Box<[_]>>();
     let preset_gains = presets_toml.equaliser.iter().map(|e| {
         let gains = e.gains;
         quote!([#(#gains,)*])
@@ -48,6 +53,17 @@ pub fn impl_read_equaliser_presets_as(ty: &Ident) -> TokenStream {
         }
 
         impl #ty {
+            const NAMES: [&'static str; #presets_len] = [#(#preset_names_strs,)*];
+
+            fn from_name(name: &str) -> Option<Self> {
+                #(
+                    if #preset_names_strs.eq_ignore_ascii_case(name) {
+                        return Some(Self::#preset_names_idents);
+                    }
+                )*
+                None
+            }
+
             const fn gains(&self) -> [f64; 15] {
                 match self {
                     #(Self::#preset_names_idents => #preset_gains,)*
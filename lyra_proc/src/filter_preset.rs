@@ -0,0 +1,200 @@
+use heck::ToPascalCase;
+use proc_macro::TokenStream;
+use quote::{quote, ToTokens};
+use serde::Deserialize;
+use syn::Ident;
+
+#[derive(Deserialize)]
+struct Presets {
+    filter: Vec<Filters>,
+}
+
+#[derive(Deserialize)]
+struct Filters {
+    name: String,
+    timescale: Option<Timescale>,
+    rotation: Option<Rotation>,
+    tremolo: Option<TremoloVibrato>,
+    vibrato: Option<TremoloVibrato>,
+    low_pass: Option<LowPass>,
+    karaoke: Option<Karaoke>,
+    equaliser: Option<[f64; 15]>,
+}
+
+#[derive(Deserialize)]
+struct Timescale {
+    speed: Option<f64>,
+    pitch: Option<f64>,
+    rate: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct Rotation {
+    rotation_hz: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct TremoloVibrato {
+    frequency: Option<f64>,
+    depth: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct LowPass {
+    smoothing: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct Karaoke {
+    level: Option<f64>,
+    mono_level: Option<f64>,
+    filter_band: Option<f64>,
+    filter_width: Option<f64>,
+}
+
+pub fn impl_read_filter_presets_as(ty: &Ident) -> TokenStream {
+    let presets_str = include_str!("../../preset/filters.toml");
+    let presets_toml = toml::from_str::<Presets>(presets_str)
+        .unwrap_or_else(|e| panic!("parsing filters.toml failed: {e:?}"));
+
+    let preset_names_strs = presets_toml
+        .filter
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Box<[_]>>();
+    let preset_names_idents = presets_toml
+        .filter
+        .iter()
+        .map(|f| {
+            let name = f.name.to_pascal_case();
+            // identifiers can't start with a digit, which preset names such as `8D` do
+            let name = if name.starts_with(|c: char| c.is_ascii_digit()) {
+                format!("Preset{name}")
+            } else {
+                name
+            };
+            Ident::new(&name, proc_macro::Span::call_site().into())
+        })
+        .collect::<Box<_>>();
+
+    let preset_filters = presets_toml.filter.iter().map(preset_filters);
+
+    quote! {
+        use twilight_interactions::command::{CommandOption, CreateOption};
+
+        #[derive(CommandOption, CreateOption)]
+        pub enum #ty {
+            #(
+                #[option(name = #preset_names_strs, value = #preset_names_strs)]
+                #preset_names_idents,
+            )*
+        }
+
+        impl #ty {
+            pub fn from_name(name: &str) -> Option<Self> {
+                #(
+                    if #preset_names_strs.eq_ignore_ascii_case(name) {
+                        return Some(Self::#preset_names_idents);
+                    }
+                )*
+                None
+            }
+
+            pub fn filters(&self) -> lavalink_rs::model::player::Filters {
+                match self {
+                    #(Self::#preset_names_idents => #preset_filters,)*
+                }
+            }
+        }
+    }
+    .into()
+}
+
+fn opt(o: Option<f64>) -> impl ToTokens {
+    o.map_or_else(|| quote!(None), |v| quote!(Some(#v)))
+}
+
+fn preset_filters(f: &Filters) -> impl ToTokens {
+    let timescale = f.timescale.as_ref().map_or_else(
+        || quote!(None),
+        |t| {
+            let (speed, pitch, rate) = (opt(t.speed), opt(t.pitch), opt(t.rate));
+            quote!(Some(lavalink_rs::model::player::Timescale {
+                speed: #speed,
+                pitch: #pitch,
+                rate: #rate,
+            }))
+        },
+    );
+    let rotation = f.rotation.as_ref().map_or_else(
+        || quote!(None),
+        |r| {
+            let rotation_hz = opt(r.rotation_hz);
+            quote!(Some(lavalink_rs::model::player::Rotation {
+                rotation_hz: #rotation_hz,
+            }))
+        },
+    );
+    let tremolo_vibrato = |t: Option<&TremoloVibrato>| {
+        t.map_or_else(
+            || quote!(None),
+            |t| {
+                let (frequency, depth) = (opt(t.frequency), opt(t.depth));
+                quote!(Some(lavalink_rs::model::player::TremoloVibrato {
+                    frequency: #frequency,
+                    depth: #depth,
+                }))
+            },
+        )
+    };
+    let tremolo = tremolo_vibrato(f.tremolo.as_ref());
+    let vibrato = tremolo_vibrato(f.vibrato.as_ref());
+    let low_pass = f.low_pass.as_ref().map_or_else(
+        || quote!(None),
+        |l| {
+            let smoothing = opt(l.smoothing);
+            quote!(Some(lavalink_rs::model::player::LowPass {
+                smoothing: #smoothing,
+            }))
+        },
+    );
+    let karaoke = f.karaoke.as_ref().map_or_else(
+        || quote!(None),
+        |k| {
+            let (level, mono_level, filter_band, filter_width) = (
+                opt(k.level),
+                opt(k.mono_level),
+                opt(k.filter_band),
+                opt(k.filter_width),
+            );
+            quote!(Some(lavalink_rs::model::player::Karaoke {
+                level: #level,
+                mono_level: #mono_level,
+                filter_band: #filter_band,
+                filter_width: #filter_width,
+            }))
+        },
+    );
+    let equalizer = f.equaliser.map_or_else(
+        || quote!(None),
+        |gains| {
+            let bands = 0..15_u8;
+            quote!(Some(vec![
+                #(lavalink_rs::model::player::Equalizer { band: #bands, gain: #gains },)*
+            ]))
+        },
+    );
+
+    quote! {
+        lavalink_rs::model::player::Filters {
+            timescale: #timescale,
+            rotation: #rotation,
+            tremolo: #tremolo,
+            vibrato: #vibrato,
+            low_pass: #low_pass,
+            karaoke: #karaoke,
+            equalizer: #equalizer,
+            ..Default::default()
+        }
+    }
+}
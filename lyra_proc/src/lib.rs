@@ -3,6 +3,7 @@ extern crate proc_macro;
 mod command;
 mod config_access;
 mod equaliser_preset;
+mod filter_preset;
 mod model;
 
 use proc_macro::TokenStream;
@@ -27,3 +28,10 @@ pub fn read_equaliser_presets_as(ty: TokenStream) -> TokenStream {
 
     equaliser_preset::impl_read_equaliser_presets_as(&ty)
 }
+
+#[proc_macro]
+pub fn read_filter_presets_as(ty: TokenStream) -> TokenStream {
+    let ty = syn::parse_macro_input!(ty as syn::Ident);
+
+    filter_preset::impl_read_filter_presets_as(&ty)
+}